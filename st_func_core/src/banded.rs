@@ -0,0 +1,265 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// 帯行列を表す構造体
+///
+/// 対角成分の下側 `lower` 本、上側 `upper` 本の帯の内側の成分のみを保持する。
+/// せん断型モデルの剛性行列のような三重対角行列（`lower = upper = 1`）では、
+/// 密な `n×n` の行列に比べて必要な記憶領域が `3n` で済む。
+pub struct BandedMatrix {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<Vec<f64>>,
+}
+
+/// 帯行列の解法でピボットを0とみなす閾値
+const SINGULAR_TOLERANCE: f64 = 1e-12;
+
+impl BandedMatrix {
+    /// すべての成分が0の帯行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `size` - 行列のサイズ
+    /// * `lower` - 下側の帯幅
+    /// * `upper` - 上側の帯幅
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい帯行列
+    pub fn new(size: usize, lower: usize, upper: usize) -> Self {
+        BandedMatrix {
+            size,
+            lower,
+            upper,
+            data: vec![vec![0.0; lower + upper + 1]; size],
+        }
+    }
+
+    /// 3本の対角成分から三重対角行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `sub` - 下側の対角成分（サイズ `n - 1`）
+    /// * `diag` - 対角成分（サイズ `n`）
+    /// * `sup` - 上側の対角成分（サイズ `n - 1`）
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい三重対角行列
+    ///
+    /// # パニック
+    ///
+    /// 対角成分のサイズが整合しない場合にパニックする
+    pub fn tridiagonal(sub: &Vector<f64>, diag: &Vector<f64>, sup: &Vector<f64>) -> Self {
+        let n: usize = diag.len();
+        assert!(
+            sub.len() + 1 == n && sup.len() + 1 == n,
+            "対角成分のサイズが整合しません"
+        );
+        let mut m = BandedMatrix::new(n, 1, 1);
+        for i in 0..n {
+            m.set(i, i, diag[i]);
+            if i + 1 < n {
+                m.set(i + 1, i, sub[i]);
+                m.set(i, i + 1, sup[i]);
+            }
+        }
+        m
+    }
+
+    /// 密な行列の帯の内側の成分から帯行列を作成する
+    ///
+    /// 帯の外側の成分は無視する。
+    ///
+    /// # 引数
+    ///
+    /// * `matrix` - 正方行列
+    /// * `lower` - 下側の帯幅
+    /// * `upper` - 上側の帯幅
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい帯行列
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn from_dense(matrix: &Matrix<f64>, lower: usize, upper: usize) -> Self {
+        assert!(matrix.rows() == matrix.cols(), "行列が正方ではありません");
+        let n: usize = matrix.rows();
+        let mut m = BandedMatrix::new(n, lower, upper);
+        for i in 0..n {
+            for j in i.saturating_sub(lower)..(i + upper + 1).min(n) {
+                m.set(i, j, matrix[(i, j)]);
+            }
+        }
+        m
+    }
+
+    /// 密な行列に変換する
+    ///
+    /// # 戻り値
+    ///
+    /// 帯の外側の成分を0とした密な行列
+    pub fn to_dense(&self) -> Matrix<f64> {
+        Matrix::from_fn(self.size, self.size, |i, j| self.get(i, j))
+    }
+
+    /// 行列のサイズを返す
+    ///
+    /// # 戻り値
+    ///
+    /// 行列のサイズ
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// 成分を返す
+    ///
+    /// # 引数
+    ///
+    /// * `i` - 行
+    /// * `j` - 列
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `(i, j)` 成分。帯の外側の場合は0
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        if j + self.lower < i || j > i + self.upper {
+            return 0.0;
+        }
+        self.data[i][j + self.lower - i]
+    }
+
+    /// 成分を設定する
+    ///
+    /// # 引数
+    ///
+    /// * `i` - 行
+    /// * `j` - 列
+    /// * `value` - 設定する値
+    ///
+    /// # パニック
+    ///
+    /// 帯の外側の成分を設定しようとした場合にパニックする
+    pub fn set(&mut self, i: usize, j: usize, value: f64) {
+        assert!(
+            j + self.lower >= i && j <= i + self.upper,
+            "帯の外側の成分は設定できません: ({}, {})",
+            i,
+            j
+        );
+        self.data[i][j + self.lower - i] = value;
+    }
+
+    /// 連立一次方程式 `A x = b` を解く
+    ///
+    /// ピボット選択を行わないガウスの消去法を帯の内側に限って適用する。
+    /// 三重対角行列の場合はThomas法（三重対角行列アルゴリズム）と同じであり、計算量は `O(n)` となる。
+    /// ピボット選択を行わないため、対称正定値行列や対角優位な行列を対象とする。
+    ///
+    /// # 引数
+    ///
+    /// * `b` - 右辺ベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 解ベクトル
+    ///
+    /// # パニック
+    ///
+    /// 右辺ベクトルのサイズが一致しない場合、またはピボットが0となった場合にパニックする
+    pub fn solve_banded(&self, b: &Vector<f64>) -> Vector<f64> {
+        let n: usize = self.size;
+        assert_eq!(b.len(), n, "右辺ベクトルのサイズが一致しません");
+        let mut a = BandedMatrix {
+            size: n,
+            lower: self.lower,
+            upper: self.upper,
+            data: self.data.clone(),
+        };
+        let mut y: Vector<f64> = b.clone();
+
+        // 前進消去
+        for k in 0..n {
+            let pivot: f64 = a.get(k, k);
+            assert!(pivot.abs() > SINGULAR_TOLERANCE, "行列が特異です");
+            let last_col: usize = (k + self.upper).min(n - 1);
+            for i in (k + 1)..=(k + self.lower).min(n - 1) {
+                let factor: f64 = a.get(i, k) / pivot;
+                if factor == 0.0 {
+                    continue;
+                }
+                for j in k..=last_col {
+                    let value: f64 = a.get(i, j) - factor * a.get(k, j);
+                    a.set(i, j, value);
+                }
+                y[i] -= factor * y[k];
+            }
+        }
+
+        // 後退代入
+        let mut x: Vector<f64> = Vector::new(n);
+        for i in (0..n).rev() {
+            let mut sum: f64 = y[i];
+            for j in (i + 1)..=(i + self.upper).min(n - 1) {
+                sum -= a.get(i, j) * x[j];
+            }
+            x[i] = sum / a.get(i, i);
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::mdof::shear_building_stiffness;
+
+    #[test]
+    fn test_banded_get_set() {
+        let mut m = BandedMatrix::new(4, 1, 2);
+        m.set(2, 1, 3.0);
+        m.set(1, 3, -1.5);
+        assert_eq!(m.size(), 4);
+        assert_eq!(m.get(2, 1), 3.0);
+        assert_eq!(m.get(1, 3), -1.5);
+        assert_eq!(m.get(3, 0), 0.0);
+        assert_eq!(m.get(0, 3), 0.0);
+    }
+
+    #[test]
+    fn test_banded_solve_tridiagonal() {
+        let stiffness =
+            shear_building_stiffness(&Vector::from_vec(vec![400.0, 300.0, 200.0, 100.0]));
+        let banded = BandedMatrix::from_dense(&stiffness, 1, 1);
+        assert!(banded.to_dense().approx_eq(&stiffness, 0.0));
+
+        let b = Vector::from_vec(vec![1.0, 2.0, -1.0, 0.5]);
+        let x = banded.solve_banded(&b);
+        assert!(x.approx_eq(&stiffness.solve(&b), 1e-12));
+
+        let tri = BandedMatrix::tridiagonal(
+            &Vector::from_vec(vec![-1.0, -1.0]),
+            &Vector::from_vec(vec![2.0, 2.0, 2.0]),
+            &Vector::from_vec(vec![-1.0, -1.0]),
+        );
+        let x = tri.solve_banded(&Vector::from_vec(vec![1.0, 0.0, 1.0]));
+        assert!(x.approx_eq(&Vector::from_vec(vec![1.0, 1.0, 1.0]), 1e-12));
+    }
+
+    #[test]
+    fn test_banded_solve_pentadiagonal() {
+        let dense = Matrix::from_fn(6, 6, |i, j| match i.abs_diff(j) {
+            0 => 6.0,
+            1 => -2.0,
+            2 => 0.5,
+            _ => 0.0,
+        });
+        let banded = BandedMatrix::from_dense(&dense, 2, 2);
+        let b = Vector::from_fn(6, |i| i as f64 - 2.0);
+        assert!(banded.solve_banded(&b).approx_eq(&dense.solve(&b), 1e-12));
+    }
+}
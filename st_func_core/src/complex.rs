@@ -0,0 +1,156 @@
+use crate::num::{One, Zero};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// 複素数を表す構造体
+///
+/// # フィールド
+///
+/// * `re` - 実部
+/// * `im` - 虚部
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    /// 新しい複素数を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `re` - 実部
+    /// * `im` - 虚部
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい複素数
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl Complex<f64> {
+    /// 偏角から絶対値1の複素数 `exp(i theta)` を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `theta` - 偏角
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい複素数
+    pub fn from_angle(theta: f64) -> Self {
+        Complex::new(theta.cos(), theta.sin())
+    }
+
+    /// 共役複素数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 共役複素数
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// 絶対値を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 絶対値
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+}
+
+impl<T: Zero> Zero for Complex<T> {
+    /// 0を返す
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+}
+
+impl<T: Zero + One> One for Complex<T> {
+    /// 1を返す
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Add for Complex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Complex<T>;
+
+    /// 2つの複素数を加算する
+    fn add(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Complex<T>;
+
+    /// 2つの複素数を減算する
+    fn sub(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Copy,
+{
+    type Output = Complex<T>;
+
+    /// 2つの複素数を掛け算する
+    fn mul(self, other: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Complex<T>;
+
+    /// 符号を反転する
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+    }
+
+    #[test]
+    fn test_complex_conj_and_norm() {
+        let a = Complex::new(3.0, 4.0);
+        assert_eq!(a.conj(), Complex::new(3.0, -4.0));
+        assert_eq!(a.norm(), 5.0);
+    }
+
+    #[test]
+    fn test_complex_zero_one_neg() {
+        assert_eq!(<Complex<f64> as Zero>::zero(), Complex::new(0.0, 0.0));
+        assert_eq!(<Complex<f64> as One>::one(), Complex::new(1.0, 0.0));
+        assert_eq!(-Complex::new(1.0, -2.0), Complex::new(-1.0, 2.0));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::dynamic::sdof::SdofResponse;
+use crate::numeric::cumulative_trapezoid_nonuniform;
+use crate::vector::Vector;
+
+/// 1質点系のエネルギー応答を表す構造体
+///
+/// 相対座標系での運動方程式 `m ÿ + c ẏ + f = -m ÿ0` に `dy` を乗じて積分した
+/// エネルギーの釣り合い `kinetic + damping + hysteretic = input` が成り立つ。
+///
+/// # フィールド
+///
+/// * `input` - 入力エネルギー `-∫ m ÿ0 dy`
+/// * `kinetic` - 運動エネルギー `m ẏ² / 2`
+/// * `damping` - 減衰による消費エネルギー `∫ c ẏ dy`
+/// * `hysteretic` - 復元力によるエネルギー `∫ f dy`（弾性歪エネルギーと履歴吸収エネルギーの和）
+pub struct EnergyResponse {
+    pub input: Vector<f64>,
+    pub kinetic: Vector<f64>,
+    pub damping: Vector<f64>,
+    pub hysteretic: Vector<f64>,
+}
+
+/// 1質点系の応答からエネルギーの時刻歴を計算する関数
+///
+/// 各ステップの変位増分に対して [`cumulative_trapezoid_nonuniform`] により台形則で積分する。
+/// 復元力は `SdofResponse` の加速度から `f = m · absolute_acceleration - c · relative_velocity`
+/// として求めることもできる。
+///
+/// # 引数
+///
+/// * `response` - 1質点系の応答
+/// * `y0_ddot` - 地動加速度
+/// * `restoring_force` - 復元力の時刻歴
+/// * `mass` - 質量
+/// * `damping` - 減衰係数
+///
+/// # 戻り値
+///
+/// エネルギー応答を表す `EnergyResponse` 構造体
+///
+/// # パニック
+///
+/// 応答、地動加速度、復元力の長さが一致しない場合にパニックする
+pub fn energy_response(
+    response: &SdofResponse,
+    y0_ddot: &Vector<f64>,
+    restoring_force: &Vector<f64>,
+    mass: f64,
+    damping: f64,
+) -> EnergyResponse {
+    let n: usize = y0_ddot.len();
+    assert_eq!(
+        response.relative_displacement.len(),
+        n,
+        "応答、地動加速度、復元力の長さが一致しません"
+    );
+    assert_eq!(
+        restoring_force.len(),
+        n,
+        "応答、地動加速度、復元力の長さが一致しません"
+    );
+
+    let y = &response.relative_displacement;
+    let y_dot = &response.relative_velocity;
+
+    let mut kinetic: Vector<f64> = Vector::new(n);
+    for i in 0..n {
+        kinetic[i] = 0.5 * mass * y_dot[i] * y_dot[i];
+    }
+    let input: Vector<f64> = cumulative_trapezoid_nonuniform(y0_ddot, y).scale(-mass);
+    let damping_energy: Vector<f64> = cumulative_trapezoid_nonuniform(y_dot, y).scale(damping);
+    let hysteretic: Vector<f64> = cumulative_trapezoid_nonuniform(restoring_force, y);
+
+    EnergyResponse {
+        input,
+        kinetic,
+        damping: damping_energy,
+        hysteretic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::nonlinear::nonlinear_sdof_bilinear;
+    use crate::dynamic::sdof::newmark_beta;
+    use std::f64::consts::PI;
+
+    fn ground_motion(n: usize, delta_t: f64) -> Vector<f64> {
+        let mut y0_ddot: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = 3.0 * (2.0 * PI * t / 0.4).sin() * (-0.5 * t).exp();
+        }
+        y0_ddot
+    }
+
+    #[test]
+    /// 線形の1質点系でエネルギーの釣り合いが成り立つことを確認するテスト
+    fn test_energy_balance_linear() {
+        let delta_t: f64 = 0.01;
+        let mass: f64 = 2.0;
+        let stiffness: f64 = 500.0;
+        let h: f64 = 0.05;
+        let omega: f64 = (stiffness / mass).sqrt();
+        let c: f64 = 2.0 * h * omega * mass;
+        let y0_ddot = ground_motion(1000, delta_t);
+        let response = newmark_beta(&y0_ddot, delta_t, omega, h, 0.25, 0.5);
+        let restoring_force = response.relative_displacement.scale(stiffness);
+        let energy = energy_response(&response, &y0_ddot, &restoring_force, mass, c);
+
+        let max_input: f64 = energy.input.norm_inf();
+        assert!(max_input > 0.0);
+        for i in 0..y0_ddot.len() {
+            let residual: f64 =
+                energy.input[i] - energy.kinetic[i] - energy.damping[i] - energy.hysteretic[i];
+            assert!(
+                residual.abs() < 1e-10 * max_input,
+                "index: {}, residual: {}",
+                i,
+                residual
+            );
+        }
+    }
+
+    #[test]
+    /// バイリニア型の1質点系でエネルギーの釣り合いが成り立つことを確認するテスト
+    fn test_energy_balance_bilinear() {
+        let delta_t: f64 = 0.01;
+        let mass: f64 = 1.0;
+        let k_initial: f64 = 400.0;
+        let h: f64 = 0.05;
+        let c: f64 = 2.0 * h * (mass * k_initial).sqrt();
+        let y0_ddot = ground_motion(1000, delta_t);
+        let response = nonlinear_sdof_bilinear(&y0_ddot, delta_t, mass, k_initial, 40.0, 1.0, h);
+        let restoring_force =
+            response.absolute_acceleration.scale(mass) - response.relative_velocity.scale(c);
+        let energy = energy_response(&response, &y0_ddot, &restoring_force, mass, c);
+
+        let max_input: f64 = energy.input.norm_inf();
+        for i in 0..y0_ddot.len() {
+            let residual: f64 =
+                energy.input[i] - energy.kinetic[i] - energy.damping[i] - energy.hysteretic[i];
+            assert!(
+                residual.abs() < 1e-8 * max_input,
+                "index: {}, residual: {}",
+                i,
+                residual
+            );
+        }
+        // 降伏により履歴吸収エネルギーが残る
+        assert!(energy.hysteretic[y0_ddot.len() - 1] > 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "応答、地動加速度、復元力の長さが一致しません")]
+    /// 復元力の長さが地動加速度と異なる場合にパニックすることを確認するテスト
+    fn test_energy_response_length_mismatch() {
+        let delta_t: f64 = 0.01;
+        let y0_ddot = ground_motion(100, delta_t);
+        let response = newmark_beta(&y0_ddot, delta_t, 2.0 * PI, 0.05, 0.25, 0.5);
+        let restoring_force: Vector<f64> = Vector::new(99);
+        energy_response(&response, &y0_ddot, &restoring_force, 1.0, 0.0);
+    }
+}
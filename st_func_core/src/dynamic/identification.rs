@@ -0,0 +1,137 @@
+use crate::vector::Vector;
+use std::f64::consts::PI;
+
+/// 自由振動の変位時刻歴から対数減衰率を用いて減衰定数を推定する関数
+///
+/// 正の極大値（ピーク）を順に抽出し、ピークの振幅の対数 `ln x_k` を時刻 `t_k` に対して最小二乗法で直線近似する。
+/// 傾き `-h ω` と、ピークの間隔から求めた減衰固有周期 `T_d` より、対数減衰率 `δ = h ω T_d` を求め、
+/// `h = δ / √(4π² + δ²)` とする。ピークが2つの場合は `δ = ln(x_0 / x_1)` と一致する。
+///
+/// # 引数
+///
+/// * `displacement` - 自由振動の変位時刻歴
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 減衰定数。ピークが2つ未満の場合は NaN
+pub fn log_decrement_damping(displacement: &Vector<f64>, delta_t: f64) -> f64 {
+    let mut times: Vec<f64> = Vec::new();
+    let mut log_peaks: Vec<f64> = Vec::new();
+    for i in 1..displacement.len().saturating_sub(1) {
+        let x: f64 = displacement[i];
+        if x > 0.0 && x > displacement[i - 1] && x >= displacement[i + 1] {
+            times.push(i as f64 * delta_t);
+            log_peaks.push(x.ln());
+        }
+    }
+    let m: usize = times.len();
+    if m < 2 {
+        return f64::NAN;
+    }
+
+    // ln x_k = a - (h ω) t_k の最小二乗近似
+    let t_mean: f64 = times.iter().sum::<f64>() / m as f64;
+    let y_mean: f64 = log_peaks.iter().sum::<f64>() / m as f64;
+    let mut sxy: f64 = 0.0;
+    let mut sxx: f64 = 0.0;
+    for k in 0..m {
+        sxy += (times[k] - t_mean) * (log_peaks[k] - y_mean);
+        sxx += (times[k] - t_mean) * (times[k] - t_mean);
+    }
+    let decay_rate: f64 = -sxy / sxx;
+
+    let damped_period: f64 = (times[m - 1] - times[0]) / (m - 1) as f64;
+    let delta: f64 = decay_rate * damped_period; // 対数減衰率
+    delta / (4.0 * PI * PI + delta * delta).sqrt()
+}
+
+/// 伝達関数の共振ピークからハーフパワー法で減衰定数を推定する関数
+///
+/// 振幅が最大となる振動数を共振振動数 `f0` とし、その両側で振幅が `1/√2` 倍となる振動数 `f1`, `f2` を
+/// 線形補間で求めて `h = (f2 - f1) / (2 f0)` とする。
+/// 卓越した共振ピークが1つだけあり、減衰が小さい（`h` が 0.1 程度以下）ことを前提とする。
+///
+/// # 引数
+///
+/// * `freq` - 振動数（昇順）
+/// * `amplitude` - 伝達関数の振幅
+///
+/// # 戻り値
+///
+/// 減衰定数。ピークの両側で振幅が `1/√2` 倍を下回らない場合は NaN
+///
+/// # パニック
+///
+/// 振動数と振幅のサイズが一致しない場合、またはベクトルが空の場合にパニックする
+pub fn half_power_damping(freq: &Vector<f64>, amplitude: &Vector<f64>) -> f64 {
+    assert_eq!(
+        freq.len(),
+        amplitude.len(),
+        "振動数と振幅のサイズが一致しません"
+    );
+    let peak: usize = amplitude.argmax();
+    let threshold: f64 = amplitude[peak] / 2.0_f64.sqrt();
+
+    // 振幅が閾値を横切る振動数を線形補間で求める
+    let crossing = |i: usize, j: usize| {
+        let ratio: f64 = (threshold - amplitude[i]) / (amplitude[j] - amplitude[i]);
+        freq[i] + ratio * (freq[j] - freq[i])
+    };
+    let Some(lower) = (1..=peak).rev().find(|&i| amplitude[i - 1] < threshold) else {
+        return f64::NAN;
+    };
+    let Some(upper) = (peak..amplitude.len() - 1).find(|&i| amplitude[i + 1] < threshold) else {
+        return f64::NAN;
+    };
+    let f1: f64 = crossing(lower - 1, lower);
+    let f2: f64 = crossing(upper, upper + 1);
+    (f2 - f1) / (2.0 * freq[peak])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// 減衰定数が既知の減衰自由振動から減衰定数を推定するテスト
+    fn test_log_decrement_damping() {
+        let h: f64 = 0.03;
+        let omega: f64 = 2.0 * PI / 0.5;
+        let omega_d: f64 = omega * (1.0 - h * h).sqrt();
+        let delta_t: f64 = 0.001;
+        let displacement: Vector<f64> = Vector::from_fn(5000, |i| {
+            let t: f64 = i as f64 * delta_t;
+            (-h * omega * t).exp() * (omega_d * t).cos()
+        });
+        let estimated: f64 = log_decrement_damping(&displacement, delta_t);
+        assert!((estimated - h).abs() < 1e-4, "h: {}", estimated);
+    }
+
+    #[test]
+    fn test_log_decrement_damping_too_few_peaks() {
+        let displacement = Vector::from_vec(vec![0.0, 1.0, 0.5, 0.0, -0.5]);
+        assert!(log_decrement_damping(&displacement, 0.01).is_nan());
+    }
+
+    #[test]
+    /// 1質点系の伝達関数からハーフパワー法で減衰定数を推定するテスト
+    fn test_half_power_damping() {
+        let h: f64 = 0.05;
+        let f0: f64 = 2.0;
+        let freq: Vector<f64> = Vector::from_fn(4001, |k| k as f64 * 0.001);
+        let amplitude: Vector<f64> = Vector::from_fn(freq.len(), |k| {
+            let r: f64 = freq[k] / f0;
+            1.0 / ((1.0 - r * r).powi(2) + (2.0 * h * r).powi(2)).sqrt()
+        });
+        let estimated: f64 = half_power_damping(&freq, &amplitude);
+        assert!((estimated - h).abs() < 2e-3, "h: {}", estimated);
+    }
+
+    #[test]
+    fn test_half_power_damping_no_crossing() {
+        let freq = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let amplitude = Vector::from_vec(vec![0.9, 1.0, 0.5]);
+        assert!(half_power_damping(&freq, &amplitude).is_nan());
+    }
+}
@@ -0,0 +1,802 @@
+use crate::dynamic::sdof::{SdofResponse, nigam_jennings, time_axis};
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// モーダル解析により多質点系の応答を計算する関数
+///
+/// 一般化固有値問題 `K φ = ω² M φ` を解いて各モードの固有円振動数と刺激係数を求め、
+/// 各モードについて `nigam_jennings` で1質点系の応答を計算し、刺激関数を乗じて重ね合わせる。
+///
+/// # 引数
+///
+/// * `mass` - 質量行列
+/// * `stiffness` - 剛性行列
+/// * `damping_ratios` - 各モードの減衰定数。サイズは自由度数と一致させる
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 各自由度の応答を表す `SdofResponse` 構造体のベクタ
+///
+/// # パニック
+///
+/// 剛性行列と質量行列のサイズが一致しない場合、減衰定数のサイズが自由度数（質量行列の行数）と
+/// 一致しない場合、または質量行列が正定値でない場合にパニックする
+pub fn modal_response(
+    mass: &Matrix<f64>,
+    stiffness: &Matrix<f64>,
+    damping_ratios: &Vector<f64>,
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+) -> Vec<SdofResponse> {
+    assert!(
+        stiffness.shape() == mass.shape(),
+        "剛性行列と質量行列のサイズが一致しません"
+    );
+    let n: usize = mass.rows(); // 自由度数
+    assert_eq!(
+        damping_ratios.len(),
+        n,
+        "減衰定数のサイズが自由度数と一致しません"
+    );
+    let nt: usize = y0_ddot.len(); // ステップ数
+    let (eigenvalues, modes) = generalized_eigen(stiffness, mass);
+    let influence: Vector<f64> = Vector::from_vec(vec![1.0; n]); // 影響ベクトル
+    let betas: Vector<f64> = participation_factors(&modes, mass, &influence); // 刺激係数
+
+    let mut responses: Vec<SdofResponse> = (0..n)
+        .map(|_| SdofResponse {
+            time: time_axis(nt, delta_t),
+            absolute_acceleration: Vector::new(nt),
+            relative_velocity: Vector::new(nt),
+            relative_displacement: Vector::new(nt),
+        })
+        .collect();
+
+    for j in 0..n {
+        let beta: f64 = betas[j];
+        let omega: f64 = eigenvalues[j].sqrt();
+        let modal: SdofResponse = nigam_jennings(y0_ddot, delta_t, omega, damping_ratios[j]);
+        for i in 0..n {
+            let coefficient: f64 = beta * modes[(i, j)]; // 刺激関数
+            let response = &mut responses[i];
+            for k in 0..nt {
+                response.absolute_acceleration[k] += coefficient * modal.absolute_acceleration[k];
+                response.relative_velocity[k] += coefficient * modal.relative_velocity[k];
+                response.relative_displacement[k] += coefficient * modal.relative_displacement[k];
+            }
+        }
+    }
+
+    responses
+}
+
+/// 各モードの刺激係数を計算する関数
+///
+/// 第 `j` モードの刺激係数を `β_j = φ_jᵀ M r / φ_jᵀ M φ_j` とする。
+/// 影響ベクトル `r` は地動による各自由度の変位を表し、水平方向の地動に対するせん断型モデルでは
+/// すべての成分が1のベクトルとなる。
+///
+/// # 引数
+///
+/// * `modes` - 固有ベクトルを列にもつ行列
+/// * `mass` - 質量行列
+/// * `influence` - 影響ベクトル
+///
+/// # 戻り値
+///
+/// 各モードの刺激係数
+pub fn participation_factors(
+    modes: &Matrix<f64>,
+    mass: &Matrix<f64>,
+    influence: &Vector<f64>,
+) -> Vector<f64> {
+    let mut factors: Vector<f64> = Vector::new(modes.cols());
+    for j in 0..modes.cols() {
+        let (numerator, denominator) = modal_mass_terms(modes, mass, influence, j);
+        factors[j] = numerator / denominator;
+    }
+    factors
+}
+
+/// 各モードの有効質量を計算する関数
+///
+/// 第 `j` モードの有効質量を `M_j = (φ_jᵀ M r)² / φ_jᵀ M φ_j` とする。
+/// すべてのモードの有効質量の和は `rᵀ M r`（影響ベクトルの成分がすべて1の場合は総質量）に等しい。
+///
+/// # 引数
+///
+/// * `modes` - 固有ベクトルを列にもつ行列
+/// * `mass` - 質量行列
+/// * `influence` - 影響ベクトル
+///
+/// # 戻り値
+///
+/// 各モードの有効質量
+pub fn effective_modal_mass(
+    modes: &Matrix<f64>,
+    mass: &Matrix<f64>,
+    influence: &Vector<f64>,
+) -> Vector<f64> {
+    let mut masses: Vector<f64> = Vector::new(modes.cols());
+    for j in 0..modes.cols() {
+        let (numerator, denominator) = modal_mass_terms(modes, mass, influence, j);
+        masses[j] = numerator * numerator / denominator;
+    }
+    masses
+}
+
+/// 固有ベクトルを質量正規化する関数
+///
+/// 各列の固有ベクトルを一般化質量 `φ_jᵀ M φ_j` の平方根で除し、`φ_jᵀ M φ_j = 1` とする。
+/// 固有ベクトルが互いに `M` 直交であれば、正規化後は `Φᵀ M Φ = I` となる。
+///
+/// # 引数
+///
+/// * `modes` - 固有ベクトルを列にもつ行列
+/// * `mass` - 質量行列
+///
+/// # 戻り値
+///
+/// 質量正規化した固有ベクトルを列にもつ行列
+pub fn mass_normalize(modes: &Matrix<f64>, mass: &Matrix<f64>) -> Matrix<f64> {
+    let n: usize = modes.rows();
+    let mut normalized: Matrix<f64> = modes.clone();
+    for j in 0..modes.cols() {
+        let mut generalized_mass: f64 = 0.0;
+        for i in 0..n {
+            for k in 0..n {
+                generalized_mass += modes[(i, j)] * mass[(i, k)] * modes[(k, j)];
+            }
+        }
+        let factor: f64 = 1.0 / generalized_mass.sqrt();
+        for i in 0..n {
+            normalized[(i, j)] *= factor;
+        }
+    }
+    normalized
+}
+
+/// 第 `j` モードについて `φ_jᵀ M r` と `φ_jᵀ M φ_j` を計算する
+fn modal_mass_terms(
+    modes: &Matrix<f64>,
+    mass: &Matrix<f64>,
+    influence: &Vector<f64>,
+    j: usize,
+) -> (f64, f64) {
+    let n: usize = modes.rows();
+    let mut numerator: f64 = 0.0;
+    let mut denominator: f64 = 0.0;
+    for i in 0..n {
+        for k in 0..n {
+            numerator += modes[(i, j)] * mass[(i, k)] * influence[k];
+            denominator += modes[(i, j)] * mass[(i, k)] * modes[(k, j)];
+        }
+    }
+    (numerator, denominator)
+}
+
+/// 層間変位と層間変形角の時刻歴を計算する関数
+///
+/// 第 `i` 層の層間変位を `δ_i = u_i - u_(i-1)` とし、最下層は固定された基礎に対する変位 `δ_1 = u_1` とする。
+/// 層間変形角は層間変位を階高で除した `δ_i / h_i` とする。
+///
+/// # 引数
+///
+/// * `displacements` - 下層から順に並べた各質点の相対変位の時刻歴
+/// * `story_heights` - 下層から順に並べた各層の階高
+///
+/// # 戻り値
+///
+/// 各層の層間変位の時刻歴と層間変形角の時刻歴のタプル
+///
+/// # パニック
+///
+/// 質点数と階高の数が一致しない場合、または時刻歴の長さが一致しない場合にパニックする
+pub fn story_drifts(
+    displacements: &[Vector<f64>],
+    story_heights: &Vector<f64>,
+) -> (Vec<Vector<f64>>, Vec<Vector<f64>>) {
+    let n: usize = displacements.len();
+    assert_eq!(
+        n,
+        story_heights.len(),
+        "質点数と階高の数が一致しません: {} と {}",
+        n,
+        story_heights.len()
+    );
+    let mut drifts: Vec<Vector<f64>> = Vec::with_capacity(n);
+    let mut ratios: Vec<Vector<f64>> = Vec::with_capacity(n);
+    for i in 0..n {
+        let drift: Vector<f64> = if i == 0 {
+            displacements[0].clone()
+        } else {
+            displacements[i].clone() - displacements[i - 1].clone()
+        };
+        ratios.push(drift.scale(1.0 / story_heights[i]));
+        drifts.push(drift);
+    }
+    (drifts, ratios)
+}
+
+/// ベースシアの時刻歴を計算する関数
+///
+/// `SdofResponse` の `absolute_acceleration` と同じく `a = -(ÿ + ÿ_0)` で表した各質点の絶対加速度から、
+/// `V = Σ_i Σ_k M_ik a_k` を各時刻について求める。運動方程式 `M (ÿ + ÿ_0) + C ẏ + K y = 0` より、
+/// これは1層の復元力と減衰力の和（基礎に作用するせん断力）に等しい。
+/// `modal_response` の結果をそのまま与えることができる。
+///
+/// # 引数
+///
+/// * `mass` - 質量行列
+/// * `accelerations` - 下層から順に並べた各質点の絶対加速度 `-(ÿ + ÿ_0)` の時刻歴
+///
+/// # 戻り値
+///
+/// ベースシアの時刻歴
+///
+/// # パニック
+///
+/// 質量行列のサイズと質点数が一致しない場合、または各質点の時刻歴の長さが一致しない場合にパニックする
+pub fn base_shear(mass: &Matrix<f64>, accelerations: &[Vector<f64>]) -> Vector<f64> {
+    let n: usize = accelerations.len();
+    assert!(
+        mass.shape() == (n, n),
+        "質量行列のサイズと質点数が一致しません"
+    );
+    let nt: usize = accelerations.first().map_or(0, |a| a.len());
+    assert!(
+        accelerations.iter().all(|a| a.len() == nt),
+        "各質点の時刻歴の長さが一致しません"
+    );
+    let mut shear: Vector<f64> = Vector::new(nt);
+    for t in 0..nt {
+        for i in 0..n {
+            for k in 0..n {
+                shear[t] += mass[(i, k)] * accelerations[k][t];
+            }
+        }
+    }
+    shear
+}
+
+/// 各モードの最大応答をSRSS法で組み合わせる関数
+///
+/// 最大応答を `R = √(Σ R_i²)` とする。各モードの固有振動数が十分に離れている場合に用いる。
+///
+/// # 引数
+///
+/// * `modal_peaks` - 各モードの最大応答
+///
+/// # 戻り値
+///
+/// 組み合わせた最大応答
+pub fn srss(modal_peaks: &Vector<f64>) -> f64 {
+    modal_peaks.norm()
+}
+
+/// 各モードの最大応答をCQC法で組み合わせる関数
+///
+/// 最大応答を `R = √(Σ_i Σ_j ρ_ij R_i R_j)` とする。モード間の相関係数は
+///
+/// `ρ_ij = 8 √(h_i h_j) (h_i + r h_j) r^(3/2) / ((1 - r²)² + 4 h_i h_j r (1 + r²) + 4 (h_i² + h_j²) r²)`
+///
+/// （`r = ω_j / ω_i`）とする。固有振動数が離れたモード間では `ρ_ij` が0に近づき、SRSS法と一致する。
+///
+/// # 引数
+///
+/// * `modal_peaks` - 各モードの最大応答
+/// * `frequencies` - 各モードの固有振動数（比のみを用いるため円振動数でもよい）
+/// * `damping` - 各モードの減衰定数
+///
+/// # 戻り値
+///
+/// 組み合わせた最大応答
+///
+/// # パニック
+///
+/// 引数のサイズが一致しない場合にパニックする
+pub fn cqc(modal_peaks: &Vector<f64>, frequencies: &Vector<f64>, damping: &Vector<f64>) -> f64 {
+    let n: usize = modal_peaks.len();
+    assert!(
+        frequencies.len() == n && damping.len() == n,
+        "モード数が一致しません"
+    );
+    let mut sum: f64 = 0.0;
+    for i in 0..n {
+        for j in 0..n {
+            let r: f64 = frequencies[j] / frequencies[i];
+            let (h_i, h_j) = (damping[i], damping[j]);
+            let rho: f64 = 8.0 * (h_i * h_j).sqrt() * (h_i + r * h_j) * r.powf(1.5)
+                / ((1.0 - r * r).powi(2)
+                    + 4.0 * h_i * h_j * r * (1.0 + r * r)
+                    + 4.0 * (h_i * h_i + h_j * h_j) * r * r);
+            sum += rho * modal_peaks[i] * modal_peaks[j];
+        }
+    }
+    sum.sqrt()
+}
+
+/// せん断型モデルの剛性行列を作成する関数
+///
+/// 第 `i` 層の層剛性を `k_i` とすると、剛性行列は対角成分が `k_i + k_(i+1)`、
+/// 隣接する層間の非対角成分が `-k_(i+1)` の三重対角行列となる（最上層では `k_(n+1) = 0`）。
+///
+/// # 引数
+///
+/// * `story_stiffness` - 下層から順に並べた各層の層剛性
+///
+/// # 戻り値
+///
+/// 剛性行列
+pub fn shear_building_stiffness(story_stiffness: &Vector<f64>) -> Matrix<f64> {
+    let n: usize = story_stiffness.len();
+    let mut stiffness: Matrix<f64> = Matrix::new_square(n);
+    for i in 0..n {
+        stiffness[(i, i)] += story_stiffness[i];
+        if i + 1 < n {
+            stiffness[(i, i)] += story_stiffness[i + 1];
+            stiffness[(i, i + 1)] = -story_stiffness[i + 1];
+            stiffness[(i + 1, i)] = -story_stiffness[i + 1];
+        }
+    }
+    stiffness
+}
+
+/// 集中質量モデルの質量行列を作成する関数
+///
+/// # 引数
+///
+/// * `masses` - 下層から順に並べた各質点の質量
+///
+/// # 戻り値
+///
+/// 質量を対角成分とする質量行列
+pub fn lumped_mass(masses: &Vector<f64>) -> Matrix<f64> {
+    Matrix::diagonal(masses)
+}
+
+/// 2つのモードの減衰定数を指定してRayleigh減衰行列を作成する関数
+///
+/// 減衰行列を `C = a0 M + a1 K` とすると、円振動数 `ω` のモードの減衰定数は
+/// `h = a0 / (2 ω) + a1 ω / 2` となる。2つのモード `i`, `j` で指定した減衰定数となるように
+///
+/// * `a0 = 2 ωi ωj (hi ωj - hj ωi) / (ωj² - ωi²)`
+/// * `a1 = 2 (hj ωj - hi ωi) / (ωj² - ωi²)`
+///
+/// とする。
+///
+/// # 引数
+///
+/// * `mass` - 質量行列
+/// * `stiffness` - 剛性行列
+/// * `omega_i` - 1つ目のモードの円振動数
+/// * `h_i` - 1つ目のモードの減衰定数
+/// * `omega_j` - 2つ目のモードの円振動数
+/// * `h_j` - 2つ目のモードの減衰定数
+///
+/// # 戻り値
+///
+/// 減衰行列
+///
+/// # パニック
+///
+/// 2つの円振動数が等しい場合、または質量行列と剛性行列のサイズが一致しない場合にパニックする
+pub fn rayleigh_damping(
+    mass: &Matrix<f64>,
+    stiffness: &Matrix<f64>,
+    omega_i: f64,
+    h_i: f64,
+    omega_j: f64,
+    h_j: f64,
+) -> Matrix<f64> {
+    assert!(omega_i != omega_j, "2つの円振動数が等しいです");
+    assert!(mass.shape() == stiffness.shape());
+    let denominator: f64 = omega_j * omega_j - omega_i * omega_i;
+    let a0: f64 = 2.0 * omega_i * omega_j * (h_i * omega_j - h_j * omega_i) / denominator;
+    let a1: f64 = 2.0 * (h_j * omega_j - h_i * omega_i) / denominator;
+
+    let mut damping: Matrix<f64> = Matrix::new(mass.rows(), mass.cols());
+    for i in 0..mass.rows() {
+        for j in 0..mass.cols() {
+            damping[(i, j)] = a0 * mass[(i, j)] + a1 * stiffness[(i, j)];
+        }
+    }
+    damping
+}
+
+/// モードごとに減衰定数を指定してモード減衰行列を作成する関数
+///
+/// 固有ベクトルを [`mass_normalize`] で質量正規化した上で
+///
+/// `C = M (Σ 2 hi ωi φi φiᵀ) M`
+///
+/// とする。質量正規化した固有ベクトルに対して `Φᵀ C Φ = diag(2 hi ωi)` となるため、
+/// Rayleigh減衰と異なり、すべてのモードで指定した減衰定数が厳密に得られる。
+/// 与えなかったモードの減衰は0となる。
+///
+/// # 引数
+///
+/// * `modes` - 固有ベクトルを列にもつ行列
+/// * `mass` - 質量行列
+/// * `damping_ratios` - 各モードの減衰定数
+/// * `frequencies` - 各モードの固有円振動数
+///
+/// # 戻り値
+///
+/// 減衰行列
+///
+/// # パニック
+///
+/// 減衰定数と固有円振動数のサイズが固有ベクトルの数と一致しない場合にパニックする
+pub fn modal_damping_matrix(
+    modes: &Matrix<f64>,
+    mass: &Matrix<f64>,
+    damping_ratios: &Vector<f64>,
+    frequencies: &Vector<f64>,
+) -> Matrix<f64> {
+    assert!(
+        damping_ratios.len() == modes.cols() && frequencies.len() == modes.cols(),
+        "減衰定数と固有円振動数のサイズが固有ベクトルの数と一致しません"
+    );
+    let n: usize = modes.rows();
+    let normalized: Matrix<f64> = mass_normalize(modes, mass);
+    let mut sum: Matrix<f64> = Matrix::new_square(n);
+    for m in 0..modes.cols() {
+        let coefficient: f64 = 2.0 * damping_ratios[m] * frequencies[m];
+        for i in 0..n {
+            for j in 0..n {
+                sum[(i, j)] += coefficient * normalized[(i, m)] * normalized[(j, m)];
+            }
+        }
+    }
+    mass.clone() * sum * mass.clone()
+}
+
+/// 一般化固有値問題 `K φ = λ M φ` を解く関数
+///
+/// 質量行列をコレスキー分解 `M = L Lᵀ` して標準固有値問題 `L⁻¹ K L⁻ᵀ ψ = λ ψ` に変換し、
+/// Jacobi法で解いた後に `φ = L⁻ᵀ ψ` として固有ベクトルを求める。
+/// 固有値は固有円振動数の2乗 `ω²` である。
+///
+/// # 引数
+///
+/// * `stiffness` - 剛性行列（対称）
+/// * `mass` - 質量行列（対称正定値）
+///
+/// # 戻り値
+///
+/// 昇順に並べた固有値と、それに対応する質量正規化された（`φᵀ M φ = I`）固有ベクトルを列にもつ行列
+///
+/// # パニック
+///
+/// 質量行列が正定値でない場合、または剛性行列が対称でない場合にパニックする
+pub fn generalized_eigen(
+    stiffness: &Matrix<f64>,
+    mass: &Matrix<f64>,
+) -> (Vector<f64>, Matrix<f64>) {
+    assert!(
+        stiffness.shape() == mass.shape(),
+        "剛性行列と質量行列のサイズが一致しません"
+    );
+    let n: usize = mass.rows();
+    let l: Matrix<f64> = cholesky(mass, n);
+    let l_inv: Matrix<f64> = l.inverse().expect("質量行列が特異です");
+    let a: Matrix<f64> = l_inv.clone() * stiffness.clone() * l_inv.transpose();
+    let (values, vectors) = a.jacobi_eigen();
+    let phi: Matrix<f64> = l_inv.transpose() * vectors;
+
+    // 固有値の昇順に並べ替える
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| values[i].total_cmp(&values[j]));
+    let mut sorted_values: Vector<f64> = Vector::new(n);
+    let mut sorted_vectors: Matrix<f64> = Matrix::new_square(n);
+    for (j, &o) in order.iter().enumerate() {
+        sorted_values[j] = values[o];
+        for i in 0..n {
+            sorted_vectors[(i, j)] = phi[(i, o)];
+        }
+    }
+    (sorted_values, sorted_vectors)
+}
+
+/// 対称正定値行列をコレスキー分解し、下三角行列 `L` を返す
+fn cholesky(a: &Matrix<f64>, n: usize) -> Matrix<f64> {
+    let mut l: Matrix<f64> = Matrix::new_square(n);
+    for j in 0..n {
+        let mut sum: f64 = a[(j, j)];
+        for k in 0..j {
+            sum -= l[(j, k)] * l[(j, k)];
+        }
+        assert!(sum > 0.0, "行列が正定値ではありません");
+        l[(j, j)] = sum.sqrt();
+        for i in (j + 1)..n {
+            let mut sum: f64 = a[(i, j)];
+            for k in 0..j {
+                sum -= l[(i, k)] * l[(j, k)];
+            }
+            l[(i, j)] = sum / l[(j, j)];
+        }
+    }
+    l
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// 2層せん断型モデルの固有値のテスト
+    ///
+    /// 各層の質量 `m`、剛性 `k` が等しい場合の固有値 `ω² = (3 ∓ √5) / 2 · k / m` と比較します。
+    fn test_generalized_eigen_two_story() {
+        let m: f64 = 2.0;
+        let k: f64 = 100.0;
+        let mass = Matrix::from_vec(vec![vec![m, 0.0], vec![0.0, m]]);
+        let stiffness = Matrix::from_vec(vec![vec![2.0 * k, -k], vec![-k, k]]);
+        let (eigenvalues, modes) = generalized_eigen(&stiffness, &mass);
+        let expected = [
+            (3.0 - 5.0_f64.sqrt()) / 2.0 * k / m,
+            (3.0 + 5.0_f64.sqrt()) / 2.0 * k / m,
+        ];
+        for j in 0..2 {
+            assert!((eigenvalues[j] - expected[j]).abs() < 1e-9);
+            // 固有ベクトルは質量正規化されている
+            let generalized_mass: f64 =
+                m * (modes[(0, j)] * modes[(0, j)] + modes[(1, j)] * modes[(1, j)]);
+            assert!((generalized_mass - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    /// 2層せん断型モデルのステップ荷重応答のテスト
+    ///
+    /// 十分に時間が経過した後の相対変位が静的解 `K⁻¹ M 1 α` に収束することを確認します。
+    fn test_modal_response_step_load() {
+        let alpha: f64 = 1.0;
+        let mass = Matrix::from_vec(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+        let stiffness = Matrix::from_vec(vec![vec![200.0, -100.0], vec![-100.0, 100.0]]);
+        let damping_ratios = Vector::from_vec(vec![0.3, 0.3]);
+        let mut y0_ddot: Vector<f64> = Vector::new(2000);
+        for i in 0..y0_ddot.len() {
+            y0_ddot[i] = -alpha;
+        }
+        let responses = modal_response(&mass, &stiffness, &damping_ratios, &y0_ddot, 0.01);
+        assert_eq!(responses.len(), 2);
+        let expected = [0.02, 0.03];
+        for i in 0..2 {
+            let last: f64 = responses[i].relative_displacement[y0_ddot.len() - 1];
+            assert!(
+                (last - expected[i]).abs() < 1e-6,
+                "dof: {}, expected: {}, actual: {}",
+                i,
+                expected[i],
+                last
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "減衰定数のサイズが自由度数と一致しません")]
+    /// 減衰定数のサイズが自由度数と異なる場合にパニックすることを確認するテスト
+    fn test_modal_response_damping_size_mismatch() {
+        let mass = lumped_mass(&Vector::from_vec(vec![1.0, 1.0]));
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![100.0, 100.0]));
+        let y0_ddot = Vector::from_vec(vec![0.0, 1.0, 0.0]);
+        modal_response(
+            &mass,
+            &stiffness,
+            &Vector::from_vec(vec![0.05]),
+            &y0_ddot,
+            0.01,
+        );
+    }
+
+    #[test]
+    /// Rayleigh減衰の2つの基準モードで減衰定数が指定値となることを確認するテスト
+    fn test_rayleigh_damping() {
+        let mass = Matrix::from_vec(vec![vec![2.0, 0.0], vec![0.0, 1.0]]);
+        let stiffness = Matrix::from_vec(vec![vec![300.0, -100.0], vec![-100.0, 100.0]]);
+        let (eigenvalues, modes) = generalized_eigen(&stiffness, &mass);
+        let omega = [eigenvalues[0].sqrt(), eigenvalues[1].sqrt()];
+        let h = [0.02, 0.05];
+        let damping = rayleigh_damping(&mass, &stiffness, omega[0], h[0], omega[1], h[1]);
+
+        // モード減衰定数 h = φᵀ C φ / (2 ω φᵀ M φ)
+        for j in 0..2 {
+            let mut c: f64 = 0.0;
+            let mut m: f64 = 0.0;
+            for p in 0..2 {
+                for q in 0..2 {
+                    c += modes[(p, j)] * damping[(p, q)] * modes[(q, j)];
+                    m += modes[(p, j)] * mass[(p, q)] * modes[(q, j)];
+                }
+            }
+            let modal_h: f64 = c / (2.0 * omega[j] * m);
+            assert!(
+                (modal_h - h[j]).abs() < 1e-12,
+                "mode: {}, h: {}",
+                j,
+                modal_h
+            );
+        }
+    }
+
+    #[test]
+    /// 2自由度系の有効質量の和が総質量に等しいことを確認するテスト
+    fn test_effective_modal_mass_sum() {
+        let mass = lumped_mass(&Vector::from_vec(vec![2.0, 1.0]));
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![300.0, 100.0]));
+        let (_, modes) = generalized_eigen(&stiffness, &mass);
+        let influence = Vector::from_vec(vec![1.0, 1.0]);
+
+        let masses = effective_modal_mass(&modes, &mass, &influence);
+        assert!((masses[0] + masses[1] - 3.0).abs() < 1e-12);
+        assert!(masses[0] > masses[1]);
+
+        // 質量正規化されたモードでは有効質量は刺激係数の2乗に等しい
+        let factors = participation_factors(&modes, &mass, &influence);
+        for j in 0..2 {
+            assert!((masses[j] - factors[j] * factors[j]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    /// 高さ方向に線形な変位分布で層間変形角が一様となることを確認するテスト
+    fn test_story_drifts_linear_profile() {
+        let heights = Vector::from_vec(vec![4.0, 3.0, 3.0]);
+        // 各時刻で u_i = c(t) · z_i （z_i は基礎からの高さ）
+        let scales = [0.0, 0.01, -0.02];
+        let elevations = [4.0, 7.0, 10.0];
+        let displacements: Vec<Vector<f64>> = elevations
+            .iter()
+            .map(|z| Vector::from_vec(scales.iter().map(|c| c * z).collect()))
+            .collect();
+        let (drifts, ratios) = story_drifts(&displacements, &heights);
+        assert_eq!(drifts.len(), 3);
+        assert!((drifts[0][1] - 0.04).abs() < 1e-12);
+        assert!((drifts[1][1] - 0.03).abs() < 1e-12);
+        for i in 0..3 {
+            for t in 0..scales.len() {
+                assert!((ratios[i][t] - scales[t]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    /// 1自由度系のベースシアが質量と絶対加速度の積に等しいことを確認するテスト
+    fn test_base_shear_single_dof() {
+        let m: f64 = 2.0;
+        let omega: f64 = 2.0 * std::f64::consts::PI / 0.5;
+        let delta_t: f64 = 0.01;
+        let y0_ddot: Vector<f64> = Vector::from_fn(200, |i| (i as f64 * delta_t * 9.0).sin());
+        let response = nigam_jennings(&y0_ddot, delta_t, omega, 0.05);
+        let mass = lumped_mass(&Vector::from_vec(vec![m]));
+        let shear = base_shear(&mass, std::slice::from_ref(&response.absolute_acceleration));
+        for t in 0..y0_ddot.len() {
+            assert!((shear[t] - m * response.absolute_acceleration[t]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    /// 減衰のない多質点系で、ベースシアが1層の復元力 `k1 y1` と一致することを確認するテスト
+    fn test_base_shear_matches_first_story_force() {
+        let k1: f64 = 300.0;
+        let mass = lumped_mass(&Vector::from_vec(vec![2.0, 1.5, 1.0]));
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![k1, 200.0, 100.0]));
+        let delta_t: f64 = 0.01;
+        let y0_ddot: Vector<f64> = Vector::from_fn(300, |i| (i as f64 * delta_t * 7.0).sin());
+        let responses = modal_response(&mass, &stiffness, &Vector::new(3), &y0_ddot, delta_t);
+        let accelerations: Vec<Vector<f64>> = responses
+            .iter()
+            .map(|r| r.absolute_acceleration.clone())
+            .collect();
+        let shear = base_shear(&mass, &accelerations);
+        for t in 0..y0_ddot.len() {
+            let expected: f64 = k1 * responses[0].relative_displacement[t];
+            assert!((shear[t] - expected).abs() < 1e-9, "t: {}", t);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "各質点の時刻歴の長さが一致しません")]
+    /// 時刻歴の長さが異なる場合にパニックすることを確認するテスト
+    fn test_base_shear_length_mismatch() {
+        let mass = lumped_mass(&Vector::from_vec(vec![1.0, 1.0]));
+        base_shear(&mass, &[Vector::new(3), Vector::new(2)]);
+    }
+
+    #[test]
+    /// 固有振動数が離れたモードではCQC法がSRSS法とほぼ一致することを確認するテスト
+    fn test_cqc_well_separated_modes() {
+        let peaks = Vector::from_vec(vec![3.0, -1.5, 0.5]);
+        let frequencies = Vector::from_vec(vec![1.0, 3.0, 9.0]);
+        let damping = Vector::from_vec(vec![0.05, 0.05, 0.05]);
+        let expected: f64 = (9.0_f64 + 2.25 + 0.25).sqrt();
+        assert!((srss(&peaks) - expected).abs() < 1e-12);
+        let combined: f64 = cqc(&peaks, &frequencies, &damping);
+        assert!(
+            (combined - expected).abs() / expected < 1e-2,
+            "cqc: {}, srss: {}",
+            combined,
+            expected
+        );
+
+        // 固有振動数が等しいモードは完全に相関する
+        let close = cqc(
+            &Vector::from_vec(vec![1.0, 1.0]),
+            &Vector::from_vec(vec![2.0, 2.0]),
+            &Vector::from_vec(vec![0.05, 0.05]),
+        );
+        assert!((close - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    /// 質量正規化後に `Φᵀ M Φ` が単位行列となることを確認するテスト
+    fn test_mass_normalize() {
+        let mass = lumped_mass(&Vector::from_vec(vec![2.0, 1.5, 1.0]));
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![300.0, 200.0, 100.0]));
+        let (_, modes) = generalized_eigen(&stiffness, &mass);
+        // 各列を任意に定数倍して正規化を崩す
+        let scales = [3.0, -0.5, 10.0];
+        let scaled = Matrix::from_fn(3, 3, |i, j| modes[(i, j)] * scales[j]);
+
+        let normalized = mass_normalize(&scaled, &mass);
+        let generalized = normalized.transpose() * mass.clone() * normalized;
+        assert!(generalized.approx_eq(&Matrix::identity(3), 1e-12));
+    }
+
+    #[test]
+    /// モード減衰行列をモード座標に変換すると指定した減衰定数が得られることを確認するテスト
+    fn test_modal_damping_matrix() {
+        let mass = lumped_mass(&Vector::from_vec(vec![2.0, 1.5, 1.0]));
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![300.0, 200.0, 100.0]));
+        let (values, modes) = generalized_eigen(&stiffness, &mass);
+        let frequencies = Vector::from_fn(3, |j| values[j].sqrt());
+        let ratios = Vector::from_vec(vec![0.02, 0.05, 0.10]);
+        // 質量正規化されていない固有ベクトルを与えても結果は変わらない
+        let scaled = Matrix::from_fn(3, 3, |i, j| modes[(i, j)] * (j as f64 + 2.0));
+
+        let damping = modal_damping_matrix(&scaled, &mass, &ratios, &frequencies);
+        assert!(damping.is_symmetric(1e-10));
+        let modal = modes.transpose() * damping * modes.clone();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected: f64 = if i == j {
+                    2.0 * ratios[i] * frequencies[i]
+                } else {
+                    0.0
+                };
+                assert!(
+                    (modal[(i, j)] - expected).abs() < 1e-10,
+                    "({}, {}): {}",
+                    i,
+                    j,
+                    modal[(i, j)]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shear_building_stiffness() {
+        let k1: f64 = 300.0;
+        let k2: f64 = 200.0;
+        let stiffness = shear_building_stiffness(&Vector::from_vec(vec![k1, k2]));
+        assert_eq!(stiffness[(0, 0)], k1 + k2);
+        assert_eq!(stiffness[(0, 1)], -k2);
+        assert_eq!(stiffness[(1, 0)], -k2);
+        assert_eq!(stiffness[(1, 1)], k2);
+    }
+
+    #[test]
+    fn test_lumped_mass() {
+        let mass = lumped_mass(&Vector::from_vec(vec![2.0, 1.5, 1.0]));
+        assert_eq!(mass[(0, 0)], 2.0);
+        assert_eq!(mass[(1, 1)], 1.5);
+        assert_eq!(mass[(2, 2)], 1.0);
+        assert_eq!(mass[(0, 1)], 0.0);
+        assert_eq!(mass[(2, 1)], 0.0);
+    }
+}
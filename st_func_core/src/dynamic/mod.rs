@@ -1 +1,64 @@
-pub mod sdof;
\ No newline at end of file
+pub mod energy;
+pub mod identification;
+pub mod mdof;
+pub mod nonlinear;
+pub mod sdof;
+pub mod spectrum;
+
+use std::f64::consts::PI;
+
+/// 固有円振動数から固有周期を求める関数
+///
+/// # 引数
+///
+/// * `omega` - 固有円振動数 [rad/s]
+///
+/// # 戻り値
+///
+/// 固有周期 `T = 2π / omega` [s]
+pub fn natural_period(omega: f64) -> f64 {
+    2.0 * PI / omega
+}
+
+/// 固有円振動数から固有振動数を求める関数
+///
+/// # 引数
+///
+/// * `omega` - 固有円振動数 [rad/s]
+///
+/// # 戻り値
+///
+/// 固有振動数 `f = omega / 2π` [Hz]
+pub fn natural_frequency(omega: f64) -> f64 {
+    omega / (2.0 * PI)
+}
+
+/// 固有円振動数と減衰定数から減衰固有周期を求める関数
+///
+/// # 引数
+///
+/// * `omega` - 固有円振動数 [rad/s]
+/// * `h` - 減衰定数（`0 <= h < 1`）
+///
+/// # 戻り値
+///
+/// 減衰固有周期 `T_d = 2π / (omega √(1 - h²))` [s]
+pub fn damped_period(omega: f64, h: f64) -> f64 {
+    natural_period(omega * (1.0 - h * h).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_period() {
+        let omega: f64 = 2.0 * PI;
+        assert!((natural_period(omega) - 1.0).abs() < 1e-15);
+        assert!((natural_frequency(omega) - 1.0).abs() < 1e-15);
+        let damped: f64 = damped_period(omega, 0.05);
+        assert!(damped > 1.0);
+        assert!((damped - 1.0 / (1.0 - 0.05_f64 * 0.05).sqrt()).abs() < 1e-15);
+        assert_eq!(damped_period(omega, 0.0), natural_period(omega));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::dynamic::natural_period;
+use crate::dynamic::sdof::{SdofResponse, time_axis};
+use crate::vector::Vector;
+use std::f64::consts::PI;
+
+/// Newmark-β法のパラメータβ（平均加速度法）
+const NEWMARK_BETA: f64 = 0.25;
+
+/// Newmark-β法のパラメータγ（平均加速度法）
+const NEWMARK_GAMMA: f64 = 0.5;
+
+/// Newton-Raphson法の収束判定に用いる変位増分の相対閾値
+const NEWTON_TOLERANCE: f64 = 1e-10;
+
+/// Newton-Raphson法の最大反復回数
+const NEWTON_MAX_ITERATIONS: usize = 50;
+
+/// 等価線形化で履歴減衰に加える粘性減衰定数
+const EQUIVALENT_LINEAR_BASE_DAMPING: f64 = 0.05;
+
+/// バイリニア型履歴の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BilinearState {
+    /// 弾性
+    Elastic,
+    /// 塑性
+    Plastic,
+}
+
+/// 移動硬化型のバイリニア型復元力特性を表す構造体
+///
+/// 降伏後の骨格曲線は `f = k_post u ± (1 - k_post / k_initial) f_y` で表され、
+/// 除荷時は初期剛性で戻る。
+struct BilinearSpring {
+    k_initial: f64,
+    k_post: f64,
+    yield_force: f64,
+    displacement: f64,
+    force: f64,
+    state: BilinearState,
+}
+
+impl BilinearSpring {
+    /// 新しい復元力特性を作成する
+    fn new(k_initial: f64, k_post: f64, yield_force: f64) -> Self {
+        BilinearSpring {
+            k_initial,
+            k_post,
+            yield_force,
+            displacement: 0.0,
+            force: 0.0,
+            state: BilinearState::Elastic,
+        }
+    }
+
+    /// 確定済みの状態から変位 `u` まで変形させたときの復元力、接線剛性、状態を返す
+    fn trial(&self, u: f64) -> (f64, f64, BilinearState) {
+        let f_trial: f64 = self.force + self.k_initial * (u - self.displacement);
+        let offset: f64 = (1.0 - self.k_post / self.k_initial) * self.yield_force;
+        let upper: f64 = self.k_post * u + offset;
+        let lower: f64 = self.k_post * u - offset;
+        if f_trial > upper {
+            (upper, self.k_post, BilinearState::Plastic)
+        } else if f_trial < lower {
+            (lower, self.k_post, BilinearState::Plastic)
+        } else {
+            (f_trial, self.k_initial, BilinearState::Elastic)
+        }
+    }
+
+    /// 変位 `u` で状態を確定する
+    fn commit(&mut self, u: f64) {
+        let (force, _, state) = self.trial(u);
+        self.displacement = u;
+        self.force = force;
+        self.state = state;
+    }
+}
+
+/// Newmark-β法により、現ステップの変位から速度と相対加速度を求める
+///
+/// # 戻り値
+///
+/// 速度と相対加速度のタプル
+fn newmark_update(
+    y_cur: f64,
+    y_pre: f64,
+    y_dot_pre: f64,
+    y_ddot_pre: f64,
+    delta_t: f64,
+    beta: f64,
+    gamma: f64,
+) -> (f64, f64) {
+    let y_ddot_cur: f64 = (y_cur - y_pre) / (beta * delta_t * delta_t)
+        - y_dot_pre / (beta * delta_t)
+        - (1.0 / (2.0 * beta) - 1.0) * y_ddot_pre;
+    let y_dot_cur: f64 = y_dot_pre + delta_t * ((1.0 - gamma) * y_ddot_pre + gamma * y_ddot_cur);
+    (y_dot_cur, y_ddot_cur)
+}
+
+/// バイリニア型復元力特性をもつ1質点系の応答を計算する関数
+///
+/// Newmark-β法（平均加速度法）で時間積分し、各ステップでNewton-Raphson法により
+/// 運動方程式 `m ÿ + c ẏ + f(y) = -m ÿ0` の釣り合いを満たす変位を求める。
+/// 減衰係数は初期剛性に比例するものとし `c = 2 h √(m k_initial)` とする。
+/// 復元力特性は移動硬化型のバイリニアとし、各ステップで弾性・塑性の状態を判定する。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `mass` - 質量
+/// * `k_initial` - 初期剛性
+/// * `k_post` - 降伏後剛性
+/// * `yield_force` - 降伏耐力
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体
+///
+/// # パニック
+///
+/// Newton-Raphson法が収束しない場合にパニックする
+pub fn nonlinear_sdof_bilinear(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    mass: f64,
+    k_initial: f64,
+    k_post: f64,
+    yield_force: f64,
+    h: f64,
+) -> SdofResponse {
+    nonlinear_sdof_bilinear_with_states(y0_ddot, delta_t, mass, k_initial, k_post, yield_force, h).0
+}
+
+/// バイリニア型復元力特性をもつ1質点系の応答と各ステップの履歴の状態を計算する関数
+///
+/// 応答は `nonlinear_sdof_bilinear` と同じであり、あわせて各ステップで確定した
+/// 弾性・塑性の状態を返す。状態が `Elastic` から `Plastic` に変わるステップが降伏の開始を表す。
+/// 時刻0の状態は `Elastic` とする。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `mass` - 質量
+/// * `k_initial` - 初期剛性
+/// * `k_post` - 降伏後剛性
+/// * `yield_force` - 降伏耐力
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体と、各ステップの履歴の状態のタプル
+///
+/// # パニック
+///
+/// Newton-Raphson法が収束しない場合にパニックする
+pub fn nonlinear_sdof_bilinear_with_states(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    mass: f64,
+    k_initial: f64,
+    k_post: f64,
+    yield_force: f64,
+    h: f64,
+) -> (SdofResponse, Vec<BilinearState>) {
+    let n: usize = y0_ddot.len();
+    let beta: f64 = NEWMARK_BETA;
+    let gamma: f64 = NEWMARK_GAMMA;
+
+    let mut y: Vector<f64> = Vector::new(n); // 変位時刻歴
+    let mut y_dot: Vector<f64> = Vector::new(n); // 速度時刻歴
+    let mut y_y0_ddot: Vector<f64> = Vector::new(n); // 加速度時刻歴
+    let mut states: Vec<BilinearState> = vec![BilinearState::Elastic; n]; // 履歴の状態
+
+    let mut y_pre: f64 = 0.0; // 前ステップの変位
+    let mut y_dot_pre: f64 = 0.0; // 前ステップの速度
+    let mut y_ddot_pre: f64 = if n > 0 { -y0_ddot[0] } else { 0.0 }; // 前ステップの相対加速度
+
+    let c: f64 = 2.0 * h * (mass * k_initial).sqrt(); // 減衰係数
+    let mut spring = BilinearSpring::new(k_initial, k_post, yield_force);
+
+    for i in 1..n {
+        let mut y_cur: f64 = y_pre;
+        let mut converged: bool = false;
+
+        for _ in 0..NEWTON_MAX_ITERATIONS {
+            let (y_dot_cur, y_ddot_cur) =
+                newmark_update(y_cur, y_pre, y_dot_pre, y_ddot_pre, delta_t, beta, gamma);
+            let (force, k_tangent, _) = spring.trial(y_cur);
+
+            let residual: f64 = -mass * y0_ddot[i] - mass * y_ddot_cur - c * y_dot_cur - force;
+            let k_effective: f64 =
+                mass / (beta * delta_t * delta_t) + c * gamma / (beta * delta_t) + k_tangent;
+            let delta_y: f64 = residual / k_effective;
+            y_cur += delta_y;
+            if delta_y.abs() <= NEWTON_TOLERANCE * y_cur.abs().max(f64::MIN_POSITIVE) {
+                converged = true;
+                break;
+            }
+        }
+        assert!(
+            converged,
+            "Newton-Raphson法が収束しませんでした: {}ステップ目",
+            i
+        );
+
+        // 収束した変位で速度・加速度・復元力を確定する
+        let (y_dot_cur, y_ddot_cur) =
+            newmark_update(y_cur, y_pre, y_dot_pre, y_ddot_pre, delta_t, beta, gamma);
+        spring.commit(y_cur);
+        states[i] = spring.state;
+
+        y[i] = y_cur;
+        y_dot[i] = y_dot_cur;
+        y_y0_ddot[i] = (c * y_dot_cur + spring.force) / mass;
+
+        y_pre = y_cur;
+        y_dot_pre = y_dot_cur;
+        y_ddot_pre = y_ddot_cur;
+    }
+
+    let response = SdofResponse {
+        time: time_axis(n, delta_t),
+        absolute_acceleration: y_y0_ddot,
+        relative_velocity: y_dot,
+        relative_displacement: y,
+    };
+    (response, states)
+}
+
+/// 割線剛性とエネルギーの釣り合いに基づき等価線形系の周期と減衰定数を求める関数
+///
+/// 完全弾塑性型の復元力特性が塑性率 `μ` の定常ループを描くとし、次の手順で等価な線形系を定める。
+///
+/// * 降伏耐力 `Fy = k_initial uy`、最大変位 `umax = μ uy` とし、等価剛性を割線剛性 `keq = Fy / umax = k_initial / μ` とする
+/// * 等価周期は `Teq = 2π √(m / keq) = T0 √μ` となる（`T0` は初期剛性による固有周期）
+/// * 1サイクルの履歴吸収エネルギー `ΔW = 4 Fy (umax - uy)` と等価剛性によるひずみエネルギー
+///   `W = keq umax² / 2` から、履歴減衰定数を `heq = ΔW / (4π W) = (2 / π) (1 - 1 / μ)` とする
+/// * 等価減衰定数は、弾性時の粘性減衰定数0.05に履歴減衰定数を加えた `h = 0.05 + heq` とする
+///
+/// 塑性率が1の場合は初期周期と粘性減衰定数0.05を返す。
+///
+/// # 引数
+///
+/// * `mass` - 質量
+/// * `k_initial` - 初期剛性
+/// * `yield_disp` - 降伏変位
+/// * `ductility` - 塑性率
+///
+/// # 戻り値
+///
+/// 等価周期と等価減衰定数のタプル
+///
+/// # パニック
+///
+/// 降伏変位が正でない場合、または塑性率が1未満の場合にパニックする
+pub fn equivalent_linear(mass: f64, k_initial: f64, yield_disp: f64, ductility: f64) -> (f64, f64) {
+    assert!(yield_disp > 0.0, "降伏変位は正の値とします: {}", yield_disp);
+    assert!(ductility >= 1.0, "塑性率は1以上とします: {}", ductility);
+    let yield_force: f64 = k_initial * yield_disp;
+    let max_disp: f64 = ductility * yield_disp;
+    let k_secant: f64 = yield_force / max_disp;
+    let period: f64 = natural_period((k_secant / mass).sqrt());
+
+    let dissipated: f64 = 4.0 * yield_force * (max_disp - yield_disp);
+    let strain_energy: f64 = 0.5 * k_secant * max_disp * max_disp;
+    let h_hysteretic: f64 = dissipated / (4.0 * PI * strain_energy);
+    (period, EQUIVALENT_LINEAR_BASE_DAMPING + h_hysteretic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::sdof::newmark_beta;
+
+    #[test]
+    /// バイリニア型復元力特性の弾性・塑性の状態遷移のテスト
+    fn test_bilinear_spring_state() {
+        let mut spring = BilinearSpring::new(100.0, 10.0, 50.0);
+        // 降伏変位 0.5 までは弾性
+        spring.commit(0.4);
+        assert_eq!(spring.state, BilinearState::Elastic);
+        assert!((spring.force - 40.0).abs() < 1e-12);
+        // 降伏後は降伏後剛性で載荷される
+        spring.commit(1.0);
+        assert_eq!(spring.state, BilinearState::Plastic);
+        assert!((spring.force - 55.0).abs() < 1e-12);
+        // 除荷は初期剛性
+        spring.commit(0.9);
+        assert_eq!(spring.state, BilinearState::Elastic);
+        assert!((spring.force - 45.0).abs() < 1e-12);
+        // 逆方向の降伏（下側の骨格曲線 f = 10 u - 45 に達する）
+        spring.commit(-0.1);
+        assert_eq!(spring.state, BilinearState::Plastic);
+        assert!((spring.force + 46.0).abs() < 1e-12);
+    }
+
+    #[test]
+    /// 入力が小さく弾性範囲に留まる場合に線形のNewmark-β法と一致することを確認するテスト
+    fn test_nonlinear_sdof_bilinear_elastic() {
+        let delta_t: f64 = 0.01;
+        let mass: f64 = 2.0;
+        let k_initial: f64 = 800.0;
+        let h: f64 = 0.05;
+        let mut y0_ddot: Vector<f64> = Vector::new(500);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = 0.1 * (2.0 * PI * t / 0.6).sin();
+        }
+        let nonlinear = nonlinear_sdof_bilinear(&y0_ddot, delta_t, mass, k_initial, 80.0, 1.0e3, h);
+        let omega: f64 = (k_initial / mass).sqrt();
+        let linear = newmark_beta(&y0_ddot, delta_t, omega, h, 0.25, 0.5);
+        for i in 0..y0_ddot.len() {
+            assert!(
+                (nonlinear.relative_displacement[i] - linear.relative_displacement[i]).abs()
+                    < 1e-12
+            );
+            assert!((nonlinear.relative_velocity[i] - linear.relative_velocity[i]).abs() < 1e-10);
+            assert!(
+                (nonlinear.absolute_acceleration[i] - linear.absolute_acceleration[i]).abs() < 1e-8
+            );
+        }
+    }
+
+    #[test]
+    /// 降伏する場合に応答変位が弾性応答より大きくなり、入力の終了後に残留変形が生じることを確認するテスト
+    fn test_nonlinear_sdof_bilinear_yielding() {
+        let delta_t: f64 = 0.01;
+        let mass: f64 = 1.0;
+        let k_initial: f64 = 400.0;
+        let mut y0_ddot: Vector<f64> = Vector::new(300);
+        for i in 0..100 {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = 10.0 * (2.0 * PI * t / 0.5).sin();
+        }
+        let (elastic, elastic_states) =
+            nonlinear_sdof_bilinear_with_states(&y0_ddot, delta_t, mass, k_initial, 40.0, 1e6, 0.2);
+        let (plastic, plastic_states) =
+            nonlinear_sdof_bilinear_with_states(&y0_ddot, delta_t, mass, k_initial, 40.0, 2.0, 0.2);
+        assert!(
+            plastic.relative_displacement.norm_inf() > elastic.relative_displacement.norm_inf()
+        );
+
+        // 入力の終了後（100ステップ以降）の変位の平均を残留変形とみなす
+        let residual = |d: &Vector<f64>| d.slice(200, 300).mean();
+        let elastic_residual: f64 = residual(&elastic.relative_displacement);
+        let plastic_residual: f64 = residual(&plastic.relative_displacement);
+        assert!(
+            plastic_residual.abs() > 10.0 * elastic_residual.abs(),
+            "plastic: {}, elastic: {}",
+            plastic_residual,
+            elastic_residual
+        );
+        assert!(plastic_residual.abs() > 0.1 * plastic.relative_displacement.norm_inf());
+
+        // 弾性の場合は降伏せず、塑性の場合は弾性から塑性への遷移が生じる
+        assert!(elastic_states.iter().all(|&s| s == BilinearState::Elastic));
+        let yield_onsets: usize = plastic_states
+            .windows(2)
+            .filter(|w| w[0] == BilinearState::Elastic && w[1] == BilinearState::Plastic)
+            .count();
+        assert!(yield_onsets > 0);
+        assert!(
+            plastic_states[200..]
+                .iter()
+                .all(|&s| s == BilinearState::Elastic)
+        );
+    }
+
+    #[test]
+    /// 塑性率1では初期周期と粘性減衰定数、塑性率4では周期2倍と履歴減衰の加算を確認するテスト
+    fn test_equivalent_linear() {
+        let mass: f64 = 2.0;
+        let k_initial: f64 = 800.0;
+        let period0: f64 = 2.0 * PI * (mass / k_initial).sqrt();
+
+        let (period, h) = equivalent_linear(mass, k_initial, 0.01, 1.0);
+        assert!((period - period0).abs() < 1e-12);
+        assert!((h - 0.05).abs() < 1e-12);
+
+        let (period, h) = equivalent_linear(mass, k_initial, 0.01, 4.0);
+        assert!((period - 2.0 * period0).abs() < 1e-12);
+        assert!((h - (0.05 + 2.0 / PI * 0.75)).abs() < 1e-12);
+    }
+}
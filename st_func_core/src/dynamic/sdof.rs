@@ -1,31 +1,262 @@
+use crate::fft::fft_convolve;
+use crate::num::Float;
 use crate::vector::Vector;
 
 /// 1質点系の応答を表す構造体
 ///
 /// # フィールド
 ///
+/// * `time` - 時刻
 /// * `absolute_acceleration` - 絶対応答加速度
 /// * `relative_velocity` - 相対応答速度
 /// * `relative_displacement` - 相対応答変位
-pub struct SdofResponse {
-    pub absolute_acceleration: Vector<f64>,
-    pub relative_velocity: Vector<f64>,
-    pub relative_displacement: Vector<f64>,
+pub struct SdofResponse<T = f64> {
+    pub time: Vector<T>,
+    pub absolute_acceleration: Vector<T>,
+    pub relative_velocity: Vector<T>,
+    pub relative_displacement: Vector<T>,
+}
+
+/// 1質点系の応答の最大値を表す構造体
+///
+/// # フィールド
+///
+/// * `max_abs_acceleration` - 絶対応答加速度の絶対値の最大値
+/// * `max_abs_velocity` - 相対応答速度の絶対値の最大値
+/// * `max_abs_displacement` - 相対応答変位の絶対値の最大値
+pub struct SdofPeaks {
+    pub max_abs_acceleration: f64,
+    pub max_abs_velocity: f64,
+    pub max_abs_displacement: f64,
+}
+
+impl SdofResponse<f64> {
+    /// 応答の絶対値の最大値を求める
+    ///
+    /// # 戻り値
+    ///
+    /// 応答の最大値を表す `SdofPeaks` 構造体
+    pub fn peaks(&self) -> SdofPeaks {
+        SdofPeaks {
+            max_abs_acceleration: self.absolute_acceleration.norm_inf(),
+            max_abs_velocity: self.relative_velocity.norm_inf(),
+            max_abs_displacement: self.relative_displacement.norm_inf(),
+        }
+    }
+}
+
+/// 時刻歴の時刻 `0, delta_t, 2 delta_t, ...` を作成する
+///
+/// # 引数
+///
+/// * `n` - ステップ数
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 時刻のベクトル
+pub(crate) fn time_axis<T: Float>(n: usize, delta_t: T) -> Vector<T> {
+    let mut time: Vector<T> = Vector::new(n);
+    for i in 0..n {
+        time[i] = T::from_f64(i as f64) * delta_t;
+    }
+    time
 }
 
 /// Nigam-Jennings法を用いて1質点系の応答を計算する関数
 ///
+/// `f32` と `f64` のいずれでも計算できる。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `omega` - 固有円振動数
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体
+pub fn nigam_jennings<T: Float>(
+    y0_ddot: &Vector<T>,
+    delta_t: T,
+    omega: T,
+    h: T,
+) -> SdofResponse<T> {
+    NigamJenningsCoefficients::new(delta_t, omega, h).response(y0_ddot)
+}
+
+/// Nigam-Jennings法の漸化式の係数を表す構造体
+///
+/// 時間刻み、固有円振動数、減衰定数が同じであれば係数も同じであるため、
+/// 複数の地震動に対して応答を計算する場合に係数の計算を再利用できる。
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NigamJenningsCoefficients<T> {
+    delta_t: T,
+    omega: T,
+    h: T,
+    a11: T,
+    a12: T,
+    a21: T,
+    a22: T,
+    b11: T,
+    b12: T,
+    b21: T,
+    b22: T,
+}
+
+impl<T: Float> NigamJenningsCoefficients<T> {
+    /// 漸化式の係数を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `delta_t` - 時間刻み
+    /// * `omega` - 固有円振動数
+    /// * `h` - 減衰定数
+    ///
+    /// # 戻り値
+    ///
+    /// 漸化式の係数
+    pub(crate) fn new(delta_t: T, omega: T, h: T) -> Self {
+        let one: T = T::one();
+        let two: T = T::from_f64(2.0);
+
+        let omega_dash: T = (one - h * h).sqrt() * omega;
+        let a11: T = (-h * omega * delta_t).exp()
+            * (h / (one - h * h).sqrt() * (omega_dash * delta_t).sin()
+                + (omega_dash * delta_t).cos());
+        let a12: T = (-h * omega * delta_t).exp() / omega_dash * (omega_dash * delta_t).sin();
+        let a21: T = -omega / (one - h * h).sqrt()
+            * (-h * omega * delta_t).exp()
+            * (omega_dash * delta_t).sin();
+        let a22: T = (-h * omega * delta_t).exp()
+            * ((omega_dash * delta_t).cos()
+                - h / (one - h * h).sqrt() * (omega_dash * delta_t).sin());
+        let b11: T = (-h * omega * delta_t).exp()
+            * (((two * h * h - one) / (omega * omega * delta_t) + h / omega)
+                * (omega_dash * delta_t).sin()
+                / omega_dash
+                + (two * h / (omega * omega * omega * delta_t) + one / (omega * omega))
+                    * (omega_dash * delta_t).cos())
+            - two * h / (omega * omega * omega * delta_t);
+        let b12: T = -(-h * omega * delta_t).exp()
+            * ((two * h * h - one) / (omega * omega * delta_t) * (omega_dash * delta_t).sin()
+                / omega_dash
+                + two * h / (omega * omega * omega * delta_t) * (omega_dash * delta_t).cos())
+            - one / (omega * omega)
+            + two * h / (omega * omega * omega * delta_t);
+        let b21: T = (-h * omega * delta_t).exp()
+            * (((two * h * h - one) / (omega * omega * delta_t) + h / omega)
+                * ((omega_dash * delta_t).cos()
+                    - h / (one - h * h).sqrt() * (omega_dash * delta_t).sin())
+                - (two * h / (omega * omega * omega * delta_t) + one / (omega * omega))
+                    * (omega_dash * (omega_dash * delta_t).sin()
+                        + h * omega * (omega_dash * delta_t).cos()))
+            + one / (omega * omega * delta_t);
+        let b22: T = -(-h * omega * delta_t).exp()
+            * ((two * h * h - one) / (omega * omega * delta_t)
+                * ((omega_dash * delta_t).cos()
+                    - h / (one - h * h).sqrt() * (omega_dash * delta_t).sin())
+                - two * h / (omega * omega * omega * delta_t)
+                    * (omega_dash * (omega_dash * delta_t).sin()
+                        + h * omega * (omega_dash * delta_t).cos()))
+            - one / (omega * omega * delta_t);
+
+        NigamJenningsCoefficients {
+            delta_t,
+            omega,
+            h,
+            a11,
+            a12,
+            a21,
+            a22,
+            b11,
+            b12,
+            b21,
+            b22,
+        }
+    }
+
+    /// 漸化式により1質点系の応答を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `y0_ddot` - 地動加速度
+    ///
+    /// # 戻り値
+    ///
+    /// 1質点系の応答を表す `SdofResponse` 構造体
+    pub(crate) fn response(&self, y0_ddot: &Vector<T>) -> SdofResponse<T> {
+        let n: usize = y0_ddot.len();
+        let (delta_t, omega, h) = (self.delta_t, self.omega, self.h);
+        let (a11, a12, a21, a22) = (self.a11, self.a12, self.a21, self.a22);
+        let (b11, b12, b21, b22) = (self.b11, self.b12, self.b21, self.b22);
+
+        let mut y: Vector<T> = Vector::new(n); // 変位時刻歴
+        let mut y_dot: Vector<T> = Vector::new(n); // 速度時刻歴
+        let mut y_y0_ddot: Vector<T> = Vector::new(n); // 加速度時刻歴
+
+        let mut y_pre: T = T::zero(); // 前ステップの変位
+        let mut y_dot_pre: T = T::zero(); // 前ステップの速度
+        let mut y0_ddot_pre: T = y0_ddot[0]; // 前ステップの地動加速度
+
+        let two: T = T::from_f64(2.0);
+
+        for i in 1..n {
+            let y0_ddot_cur: T = y0_ddot[i];
+            let y_cur: T = a11 * y_pre + a12 * y_dot_pre + b11 * y0_ddot_pre + b12 * y0_ddot_cur;
+            let y_dot_cur: T =
+                a21 * y_pre + a22 * y_dot_pre + b21 * y0_ddot_pre + b22 * y0_ddot_cur;
+            let y_y0_ddot_cur: T = two * h * omega * y_dot_cur + omega * omega * y_cur;
+
+            y[i] = y_cur;
+            y_dot[i] = y_dot_cur;
+            y_y0_ddot[i] = y_y0_ddot_cur;
+
+            y_pre = y_cur;
+            y_dot_pre = y_dot_cur;
+            y0_ddot_pre = y0_ddot_cur;
+        }
+
+        SdofResponse {
+            time: time_axis(n, delta_t),
+            absolute_acceleration: y_y0_ddot,
+            relative_velocity: y_dot,
+            relative_displacement: y,
+        }
+    }
+}
+
+/// Newmark-β法を用いて1質点系の応答を計算する関数
+///
+/// `beta = 0.25, gamma = 0.5` とすると平均加速度法、`beta = 1/6, gamma = 0.5` とすると線形加速度法になる。
+///
+/// # 安定性
+///
+/// * `2 * beta >= gamma >= 0.5` を満たす場合は無条件安定となる（平均加速度法など）
+/// * 線形加速度法は条件付き安定であり、`delta_t / T <= 0.551` （`T` は固有周期）を満たす必要がある
+/// * `gamma > 0.5` とすると数値減衰が生じ、`gamma < 0.5` とすると負の数値減衰が生じて発散しやすくなる
+///
 /// # 引数
 ///
 /// * `y0_ddot` - 地動加速度
 /// * `delta_t` - 時間刻み
 /// * `omega` - 固有円振動数
 /// * `h` - 減衰定数
+/// * `beta` - Newmark-β法のパラメータβ
+/// * `gamma` - Newmark-β法のパラメータγ
 ///
 /// # 戻り値
 ///
 /// 1質点系の応答を表す `SdofResponse` 構造体
-pub fn nigam_jennings(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -> SdofResponse {
+pub fn newmark_beta(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    omega: f64,
+    h: f64,
+    beta: f64,
+    gamma: f64,
+) -> SdofResponse {
     let n: usize = y0_ddot.len();
 
     let mut y: Vector<f64> = Vector::new(n); // 変位時刻歴
@@ -34,51 +265,27 @@ pub fn nigam_jennings(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -
 
     let mut y_pre: f64 = 0.0; // 前ステップの変位
     let mut y_dot_pre: f64 = 0.0; // 前ステップの速度
-    let mut y0_ddot_pre: f64 = y0_ddot[0]; // 前ステップの地動加速度
-
-    let omega_dash: f64 = (1.0 - h * h).sqrt() * omega;
-    let a11: f64 = (-h * omega * delta_t).exp()
-        * (h / (1.0 - h * h).sqrt() * (omega_dash * delta_t).sin() + (omega_dash * delta_t).cos());
-    let a12: f64 = (-h * omega * delta_t).exp() / omega_dash * (omega_dash * delta_t).sin();
-    let a21: f64 =
-        -omega / (1.0 - h * h).sqrt() * (-h * omega * delta_t).exp() * (omega_dash * delta_t).sin();
-    let a22: f64 = (-h * omega * delta_t).exp()
-        * ((omega_dash * delta_t).cos() - h / (1.0 - h * h).sqrt() * (omega_dash * delta_t).sin());
-    let b11: f64 = (-h * omega * delta_t).exp()
-        * (((2.0 * h * h - 1.0) / (omega * omega * delta_t) + h / omega)
-            * (omega_dash * delta_t).sin()
-            / omega_dash
-            + (2.0 * h / (omega * omega * omega * delta_t)
-                + 1.0 / (omega * omega) * (omega_dash * delta_t).cos()))
-        - 2.0 * h / (omega * omega * omega * delta_t);
-    let b12: f64 = -(-h * omega * delta_t).exp()
-        * ((2.0 * h * h - 1.0) / (omega * omega * delta_t) * (omega_dash * delta_t).sin()
-            / omega_dash
-            + 2.0 * h / (omega * omega * omega * delta_t) * (omega_dash * delta_t).cos())
-        - 1.0 / (omega * omega)
-        + 2.0 * h / (omega * omega * omega * delta_t);
-    let b21: f64 = (-h * omega * delta_t).exp()
-        * (((2.0 * h * h - 1.0) / (omega * omega * delta_t) + h / omega)
-            * ((omega_dash * delta_t).cos()
-                - h / (1.0 - h * h).sqrt() * (omega_dash * delta_t).sin())
-            - (2.0 * h / (omega * omega * omega * delta_t) + 1.0 / (omega * omega))
-                * (omega_dash * (omega_dash * delta_t).sin()
-                    + h * omega * (omega_dash * delta_t).cos()))
-        + 1.0 / (omega * omega * delta_t);
-    let b22: f64 = -(-h * omega * delta_t).exp()
-        * ((2.0 * h * h - 1.0) / (omega * omega * delta_t)
-            * ((omega_dash * delta_t).cos()
-                - h / (1.0 - h * h).sqrt() * (omega_dash * delta_t).sin())
-            - 2.0 * h / (omega * omega * omega * delta_t)
-                * (omega_dash * (omega_dash * delta_t).sin()
-                    + h * omega * (omega_dash * delta_t).cos()))
-        - 1.0 / (omega * omega * delta_t);
+    let mut y_ddot_pre: f64 = if n > 0 { -y0_ddot[0] } else { 0.0 }; // 前ステップの相対加速度
+
+    let c: f64 = 2.0 * h * omega; // 単位質量あたりの減衰係数
+    let k: f64 = omega * omega; // 単位質量あたりの剛性
+    let k_hat: f64 = k + gamma / (beta * delta_t) * c + 1.0 / (beta * delta_t * delta_t);
 
     for i in 1..n {
-        let y0_ddot_cur: f64 = y0_ddot[i];
-        let y_cur: f64 = a11 * y_pre + a12 * y_dot_pre + b11 * y0_ddot_pre + b12 * y0_ddot_cur;
-        let y_dot_cur: f64 = a21 * y_pre + a22 * y_dot_pre + b21 * y0_ddot_pre + b22 * y0_ddot_cur;
-        let y_y0_ddot_cur: f64 = 2.0 * h * omega * y_dot_cur + omega * omega * y_cur;
+        let p_hat: f64 = -y0_ddot[i]
+            + (y_pre / (beta * delta_t * delta_t)
+                + y_dot_pre / (beta * delta_t)
+                + (1.0 / (2.0 * beta) - 1.0) * y_ddot_pre)
+            + c * (gamma / (beta * delta_t) * y_pre
+                + (gamma / beta - 1.0) * y_dot_pre
+                + delta_t * (gamma / (2.0 * beta) - 1.0) * y_ddot_pre);
+        let y_cur: f64 = p_hat / k_hat;
+        let y_ddot_cur: f64 = (y_cur - y_pre) / (beta * delta_t * delta_t)
+            - y_dot_pre / (beta * delta_t)
+            - (1.0 / (2.0 * beta) - 1.0) * y_ddot_pre;
+        let y_dot_cur: f64 =
+            y_dot_pre + delta_t * ((1.0 - gamma) * y_ddot_pre + gamma * y_ddot_cur);
+        let y_y0_ddot_cur: f64 = c * y_dot_cur + k * y_cur;
 
         y[i] = y_cur;
         y_dot[i] = y_dot_cur;
@@ -86,10 +293,203 @@ pub fn nigam_jennings(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -
 
         y_pre = y_cur;
         y_dot_pre = y_dot_cur;
-        y0_ddot_pre = y0_ddot_cur;
+        y_ddot_pre = y_ddot_cur;
+    }
+
+    SdofResponse {
+        time: time_axis(n, delta_t),
+        absolute_acceleration: y_y0_ddot,
+        relative_velocity: y_dot,
+        relative_displacement: y,
+    }
+}
+
+/// Wilson-θ法を用いて1質点系の応答を計算する関数
+///
+/// 加速度が `θ delta_t` の区間で線形に変化すると仮定して `t + θ delta_t` での釣り合いを解き、
+/// その結果を内挿して `t + delta_t` での応答を求める。
+///
+/// # パラメータθ
+///
+/// * `theta = 1.0` のときは線形加速度法と一致し、条件付き安定となる
+/// * `theta >= 1.37` で無条件安定となり、一般に `theta = 1.4` が用いられる
+/// * θを大きくするほど高振動数成分に対する数値減衰が大きくなり、周期の伸びも大きくなる
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `omega` - 固有円振動数
+/// * `h` - 減衰定数
+/// * `theta` - Wilson-θ法のパラメータθ
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体
+pub fn wilson_theta(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    omega: f64,
+    h: f64,
+    theta: f64,
+) -> SdofResponse {
+    let n: usize = y0_ddot.len();
+
+    let mut y: Vector<f64> = Vector::new(n); // 変位時刻歴
+    let mut y_dot: Vector<f64> = Vector::new(n); // 速度時刻歴
+    let mut y_y0_ddot: Vector<f64> = Vector::new(n); // 加速度時刻歴
+
+    let mut y_pre: f64 = 0.0; // 前ステップの変位
+    let mut y_dot_pre: f64 = 0.0; // 前ステップの速度
+    let mut y_ddot_pre: f64 = if n > 0 { -y0_ddot[0] } else { 0.0 }; // 前ステップの相対加速度
+
+    let c: f64 = 2.0 * h * omega; // 単位質量あたりの減衰係数
+    let k: f64 = omega * omega; // 単位質量あたりの剛性
+    let tau: f64 = theta * delta_t; // 拡張した時間刻み
+    let k_hat: f64 = k + 6.0 / (tau * tau) + 3.0 * c / tau;
+
+    for i in 1..n {
+        // 外力 -ÿ0 の増分をθ倍に外挿する
+        let delta_p_hat: f64 = -theta * (y0_ddot[i] - y0_ddot[i - 1])
+            + (6.0 / tau + 3.0 * c) * y_dot_pre
+            + (3.0 + tau * c / 2.0) * y_ddot_pre;
+        let delta_y_theta: f64 = delta_p_hat / k_hat;
+        let delta_y_ddot_theta: f64 =
+            6.0 / (tau * tau) * delta_y_theta - 6.0 / tau * y_dot_pre - 3.0 * y_ddot_pre;
+        let delta_y_ddot: f64 = delta_y_ddot_theta / theta;
+        let delta_y_dot: f64 = delta_t * y_ddot_pre + delta_t / 2.0 * delta_y_ddot;
+        let delta_y: f64 = delta_t * y_dot_pre
+            + delta_t * delta_t / 2.0 * y_ddot_pre
+            + delta_t * delta_t / 6.0 * delta_y_ddot;
+
+        let y_cur: f64 = y_pre + delta_y;
+        let y_dot_cur: f64 = y_dot_pre + delta_y_dot;
+        let y_ddot_cur: f64 = y_ddot_pre + delta_y_ddot;
+
+        y[i] = y_cur;
+        y_dot[i] = y_dot_cur;
+        y_y0_ddot[i] = c * y_dot_cur + k * y_cur;
+
+        y_pre = y_cur;
+        y_dot_pre = y_dot_cur;
+        y_ddot_pre = y_ddot_cur;
+    }
+
+    SdofResponse {
+        time: time_axis(n, delta_t),
+        absolute_acceleration: y_y0_ddot,
+        relative_velocity: y_dot,
+        relative_displacement: y,
+    }
+}
+
+/// 中央差分法を用いて1質点系の応答を計算する関数
+///
+/// 陽解法であり、各ステップで連立方程式を解く必要がない。
+/// 初期変位・初期速度を0とし、初期加速度 `-y0_ddot[0]` から仮想的な前ステップの変位
+/// `y_(-1) = delta_t² / 2 · ÿ_0` を求めて計算を開始する。
+///
+/// # 安定性
+///
+/// 条件付き安定であり、`delta_t < 2 / omega` （`delta_t < T / π`）を満たす必要がある。
+/// 精度を確保するには、さらに十分小さな時間刻みとすることが望ましい。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `omega` - 固有円振動数
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体
+pub fn central_difference(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -> SdofResponse {
+    let n: usize = y0_ddot.len();
+
+    let mut y: Vector<f64> = Vector::new(n); // 変位時刻歴
+    let mut y_dot: Vector<f64> = Vector::new(n); // 速度時刻歴
+    let mut y_y0_ddot: Vector<f64> = Vector::new(n); // 加速度時刻歴
+
+    let k_hat: f64 = 1.0 / (delta_t * delta_t) + h * omega / delta_t;
+    let a: f64 = 1.0 / (delta_t * delta_t) - h * omega / delta_t;
+    let b: f64 = omega * omega - 2.0 / (delta_t * delta_t);
+
+    let y_ddot_0: f64 = if n > 0 { -y0_ddot[0] } else { 0.0 }; // 初期加速度
+    let mut y_pre: f64 = delta_t * delta_t / 2.0 * y_ddot_0; // 前ステップの変位
+    let mut y_cur: f64 = 0.0; // 現ステップの変位
+
+    for i in 0..n {
+        let y_next: f64 = (-y0_ddot[i] - a * y_pre - b * y_cur) / k_hat;
+        let y_dot_cur: f64 = (y_next - y_pre) / (2.0 * delta_t);
+
+        y[i] = y_cur;
+        y_dot[i] = y_dot_cur;
+        y_y0_ddot[i] = 2.0 * h * omega * y_dot_cur + omega * omega * y_cur;
+
+        y_pre = y_cur;
+        y_cur = y_next;
+    }
+
+    SdofResponse {
+        time: time_axis(n, delta_t),
+        absolute_acceleration: y_y0_ddot,
+        relative_velocity: y_dot,
+        relative_displacement: y,
+    }
+}
+
+/// Duhamel積分（畳み込み積分）を用いて1質点系の応答を計算する関数
+///
+/// 単位衝撃応答関数 `g(t) = exp(-h·ω·t)·sin(ω_d·t) / ω_d`（`ω_d = ω·√(1 - h²)`）と
+/// 地動加速度との畳み込み `y(t) = -∫ ÿ_0(τ)·g(t - τ) dτ` を台形則で数値積分する。
+/// 速度は `g` の導関数との畳み込みで求める。
+///
+/// 台形則の和は、数列の畳み込み `Σ ÿ_0(j)·g(i - j)` から両端の項の半分を差し引いたものとなる。
+/// 畳み込みは [`fft_convolve`] で計算するため、計算量は O(n log n) である。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `omega` - 固有円振動数
+/// * `h` - 減衰定数（`0 <= h < 1`）
+///
+/// # 戻り値
+///
+/// 1質点系の応答を表す `SdofResponse` 構造体
+pub fn duhamel(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -> SdofResponse {
+    let n: usize = y0_ddot.len();
+    let omega_d: f64 = omega * (1.0 - h * h).sqrt();
+
+    // 単位衝撃応答関数とその導関数
+    let mut g: Vector<f64> = Vector::new(n);
+    let mut g_dot: Vector<f64> = Vector::new(n);
+    for i in 0..n {
+        let t: f64 = i as f64 * delta_t;
+        let decay: f64 = (-h * omega * t).exp();
+        g[i] = decay * (omega_d * t).sin() / omega_d;
+        g_dot[i] = decay * ((omega_d * t).cos() - h * omega / omega_d * (omega_d * t).sin());
+    }
+
+    let mut y: Vector<f64> = Vector::new(n); // 変位時刻歴
+    let mut y_dot: Vector<f64> = Vector::new(n); // 速度時刻歴
+    let mut y_y0_ddot: Vector<f64> = Vector::new(n); // 加速度時刻歴
+
+    let conv_g: Vector<f64> = fft_convolve(y0_ddot, &g);
+    let conv_g_dot: Vector<f64> = fft_convolve(y0_ddot, &g_dot);
+
+    for i in 1..n {
+        // 台形則の両端の重み 0.5 を補正する
+        let disp: f64 = conv_g[i] - 0.5 * (y0_ddot[0] * g[i] + y0_ddot[i] * g[0]);
+        let vel: f64 = conv_g_dot[i] - 0.5 * (y0_ddot[0] * g_dot[i] + y0_ddot[i] * g_dot[0]);
+        y[i] = -disp * delta_t;
+        y_dot[i] = -vel * delta_t;
+        y_y0_ddot[i] = 2.0 * h * omega * y_dot[i] + omega * omega * y[i];
     }
 
     SdofResponse {
+        time: time_axis(n, delta_t),
         absolute_acceleration: y_y0_ddot,
         relative_velocity: y_dot,
         relative_displacement: y,
@@ -99,10 +499,10 @@ pub fn nigam_jennings(y0_ddot: &Vector<f64>, delta_t: f64, omega: f64, h: f64) -
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::csv::write_response_csv;
     use std::env;
     use std::f64::consts::PI;
-    use std::fs::File;
-    use std::io::{BufWriter, Write};
+    use std::path::Path;
 
     #[test]
 
@@ -128,23 +528,136 @@ mod tests {
         }
         let response: SdofResponse = nigam_jennings(&y0_ddot, delta_t, omega, h);
         let tolerance: f64 = 1e-4; // 許容誤差
+        assert_eq!(response.time.len(), y0_ddot.len());
+        assert_eq!(response.time[0], 0.0);
 
         // 環境変数をチェックしてCSVファイルに書き出すかどうかを決定
         let test_name = "test_nigam_jennings_step_load";
-        let write_csv = env::var("WRITE_CSV").map_or(false, |val| val == test_name);
-        let mut writer: Option<BufWriter<File>> = None;
-        if write_csv {
-            let output_dir = "test_output"; // テスト出力用のディレクトリ
+        if env::var("WRITE_CSV").is_ok_and(|val| val == test_name) {
+            let output_dir = Path::new("test_output"); // テスト出力用のディレクトリ
             std::fs::create_dir_all(output_dir).expect("ディレクトリ作成に失敗しました");
-            let file_path = format!("{}/{}.csv", output_dir, test_name);
-            let file = File::create(file_path).expect("ファイル作成に失敗しました");
-            writer = Some(BufWriter::new(file));
-            writeln!(writer.as_mut().unwrap(), "index,expected,actual")
-                .expect("ヘッダーの書き込みに失敗しました");
+            let file_path = output_dir.join(format!("{}.csv", test_name));
+            write_response_csv(&file_path, &response, delta_t)
+                .expect("CSVファイルの書き込みに失敗しました");
         }
 
+        for i in 0..response.relative_displacement.len() {
+            let t: f64 = response.time[i];
+            let expected: f64 = alpha / (omega * omega)
+                * (1.0
+                    - (-h * omega * t).exp()
+                        * ((omega_dash * t).cos()
+                            + h / (1.0 - h * h).sqrt() * (omega_dash * t).sin()));
+            assert!(
+                (expected - response.relative_displacement[i]).abs() < tolerance,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected,
+                response.relative_displacement[i]
+            );
+        }
+    }
+
+    #[test]
+    /// 減衰の大きいNigam-Jennings法のステップ荷重応答のテスト
+    ///
+    /// 減衰定数0.3でステップ荷重を与え、相対応答変位が理論式と一致し、
+    /// 十分な時間の経過後に静的変位 `α / ω²` に収束することを確認します。
+    /// 係数 `b11` の誤りは減衰が大きいほど静的変位の誤差として現れるため、その回帰テストとします。
+    fn test_nigam_jennings_step_load_high_damping() {
+        let alpha: f64 = 3.0;
+        let omega: f64 = 2.0 * PI / 0.1;
+        let h: f64 = 0.3;
+        let omega_dash: f64 = (1.0 - h * h).sqrt() * omega;
+        let delta_t: f64 = 0.01;
+        let y0_ddot: Vector<f64> = Vector::from_vec(vec![-alpha; 200]);
+        let response: SdofResponse = nigam_jennings(&y0_ddot, delta_t, omega, h);
+        let static_displacement: f64 = alpha / (omega * omega);
+
         for i in 0..response.relative_displacement.len() {
             let t: f64 = i as f64 * delta_t;
+            let expected: f64 = static_displacement
+                * (1.0
+                    - (-h * omega * t).exp()
+                        * ((omega_dash * t).cos()
+                            + h / (1.0 - h * h).sqrt() * (omega_dash * t).sin()));
+            assert!(
+                (expected - response.relative_displacement[i]).abs() < 1e-6 * static_displacement,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected,
+                response.relative_displacement[i]
+            );
+        }
+        let last: f64 = response.relative_displacement[199];
+        assert!((last / static_displacement - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    /// `f32` でのNigam-Jennings法を用いたステップ荷重応答のテスト
+    ///
+    /// `f32` で計算した相対応答変位が理論式および `f64` での計算結果と
+    /// `f32` の精度に応じた許容誤差内で一致することを確認します。
+    fn test_nigam_jennings_step_load_f32() {
+        let alpha: f32 = 3.0;
+        let omega: f32 = 2.0 * std::f32::consts::PI / 0.1;
+        let h: f32 = 0.05;
+        let omega_dash: f32 = (1.0 - h * h).sqrt() * omega;
+        let delta_t: f32 = 0.01;
+        let mut y0_ddot: Vector<f32> = Vector::<f32>::new(100);
+        let mut y0_ddot_f64: Vector<f64> = Vector::<f64>::new(100);
+        for i in 0..y0_ddot.len() {
+            y0_ddot[i] = -alpha;
+            y0_ddot_f64[i] = -alpha as f64;
+        }
+        let response: SdofResponse<f32> = nigam_jennings(&y0_ddot, delta_t, omega, h);
+        let response_f64: SdofResponse<f64> =
+            nigam_jennings(&y0_ddot_f64, delta_t as f64, omega as f64, h as f64);
+        let amplitude: f32 = alpha / (omega * omega);
+        let tolerance: f32 = 1e-4 * amplitude; // 許容誤差
+
+        for i in 0..response.relative_displacement.len() {
+            let t: f32 = response.time[i];
+            let expected: f32 = amplitude
+                * (1.0
+                    - (-h * omega * t).exp()
+                        * ((omega_dash * t).cos()
+                            + h / (1.0 - h * h).sqrt() * (omega_dash * t).sin()));
+            let actual: f32 = response.relative_displacement[i];
+            assert!(
+                (expected - actual).abs() < tolerance,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected,
+                actual
+            );
+            assert!(
+                (actual as f64 - response_f64.relative_displacement[i]).abs() < tolerance as f64
+            );
+        }
+    }
+
+    #[test]
+    /// Newmark-β法（平均加速度法）を用いたステップ荷重応答のテスト
+    ///
+    /// 周期の伸びによる誤差を抑えるため、Nigam-Jennings法のテストより細かい時間刻みを用いて
+    /// 理論式で算出した相対応答変位と比較します。
+    fn test_newmark_beta_step_load() {
+        let alpha: f64 = 3.0;
+        let t: f64 = 0.1;
+        let omega: f64 = 2.0 * PI / t;
+        let h: f64 = 0.05;
+        let omega_dash: f64 = (1.0 - h * h).sqrt() * omega;
+        let delta_t: f64 = 0.001;
+        let mut y0_ddot: Vector<f64> = Vector::<f64>::new(1000);
+        for i in 0..y0_ddot.len() {
+            y0_ddot[i] = -alpha;
+        }
+        let response: SdofResponse = newmark_beta(&y0_ddot, delta_t, omega, h, 0.25, 0.5);
+        let tolerance: f64 = 5e-5; // 許容誤差
+
+        for i in 0..response.relative_displacement.len() {
+            let t: f64 = response.time[i];
             let expected: f64 = alpha / (omega * omega)
                 * (1.0
                     - (-h * omega * t).exp()
@@ -157,15 +670,131 @@ mod tests {
                 expected,
                 response.relative_displacement[i]
             );
-            // CSVファイルにデータを書き込む
-            if let Some(writer) = writer.as_mut() {
-                writeln!(
-                    writer,
-                    "{},{},{}",
-                    i, expected, response.relative_displacement[i]
-                )
-                .expect("データの書き込みに失敗しました");
-            }
+        }
+    }
+
+    #[test]
+    /// 安定な時間刻みで中央差分法の応答がNigam-Jennings法とほぼ一致することを確認するテスト
+    fn test_central_difference() {
+        let delta_t: f64 = 0.001;
+        let omega: f64 = 2.0 * PI / 0.5;
+        let h: f64 = 0.05;
+        assert!(delta_t < 2.0 / omega);
+        let mut y0_ddot: Vector<f64> = Vector::new(3000);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin();
+        }
+        let expected = nigam_jennings(&y0_ddot, delta_t, omega, h);
+        let actual = central_difference(&y0_ddot, delta_t, omega, h);
+        let peak: f64 = expected.relative_displacement.norm_inf();
+        for i in 0..y0_ddot.len() {
+            assert!(
+                (expected.relative_displacement[i] - actual.relative_displacement[i]).abs()
+                    < 1e-2 * peak,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected.relative_displacement[i],
+                actual.relative_displacement[i]
+            );
+        }
+    }
+
+    #[test]
+    /// Wilson-θ法（θ = 1.4）を用いたステップ荷重応答のテスト
+    fn test_wilson_theta_step_load() {
+        let alpha: f64 = 3.0;
+        let omega: f64 = 2.0 * PI / 0.1;
+        let h: f64 = 0.05;
+        let omega_dash: f64 = (1.0 - h * h).sqrt() * omega;
+        let delta_t: f64 = 0.001;
+        let mut y0_ddot: Vector<f64> = Vector::<f64>::new(1000);
+        for i in 0..y0_ddot.len() {
+            y0_ddot[i] = -alpha;
+        }
+        let response: SdofResponse = wilson_theta(&y0_ddot, delta_t, omega, h, 1.4);
+        let tolerance: f64 = 5e-5; // 許容誤差
+
+        for i in 0..response.relative_displacement.len() {
+            let t: f64 = response.time[i];
+            let expected: f64 = alpha / (omega * omega)
+                * (1.0
+                    - (-h * omega * t).exp()
+                        * ((omega_dash * t).cos()
+                            + h / (1.0 - h * h).sqrt() * (omega_dash * t).sin()));
+            assert!(
+                (expected - response.relative_displacement[i]).abs() < tolerance,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected,
+                response.relative_displacement[i]
+            );
+        }
+    }
+
+    #[test]
+    /// ステップ荷重応答の最大変位が理論値 `α / ω² (1 + exp(-hπ / √(1 - h²)))` と一致することを確認するテスト
+    fn test_sdof_peaks_step_load() {
+        let alpha: f64 = 3.0;
+        let omega: f64 = 2.0 * PI / 0.1;
+        let h: f64 = 0.05;
+        let delta_t: f64 = 0.0001;
+        let mut y0_ddot: Vector<f64> = Vector::<f64>::new(2000);
+        for i in 0..y0_ddot.len() {
+            y0_ddot[i] = -alpha;
+        }
+        let response: SdofResponse = nigam_jennings(&y0_ddot, delta_t, omega, h);
+        let peaks: SdofPeaks = response.peaks();
+        let expected: f64 =
+            alpha / (omega * omega) * (1.0 + (-h * PI / (1.0 - h * h).sqrt()).exp());
+        assert!(
+            (peaks.max_abs_displacement - expected).abs() < 1e-5 * expected,
+            "expected: {}, actual: {}",
+            expected,
+            peaks.max_abs_displacement
+        );
+        assert_eq!(
+            peaks.max_abs_velocity,
+            response.relative_velocity.norm_inf()
+        );
+        assert_eq!(
+            peaks.max_abs_acceleration,
+            response.absolute_acceleration.norm_inf()
+        );
+    }
+
+    #[test]
+    /// Duhamel積分による応答がNigam-Jennings法とほぼ一致することを確認するテスト
+    fn test_duhamel() {
+        let delta_t: f64 = 0.001;
+        let omega: f64 = 2.0 * PI / 0.5;
+        let h: f64 = 0.05;
+        let mut y0_ddot: Vector<f64> = Vector::new(2000);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin();
+        }
+        let expected = nigam_jennings(&y0_ddot, delta_t, omega, h);
+        let actual = duhamel(&y0_ddot, delta_t, omega, h);
+        let peak_disp: f64 = expected.relative_displacement.norm_inf();
+        let peak_vel: f64 = expected.relative_velocity.norm_inf();
+        for i in 0..y0_ddot.len() {
+            assert!(
+                (expected.relative_displacement[i] - actual.relative_displacement[i]).abs()
+                    < 1e-3 * peak_disp,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected.relative_displacement[i],
+                actual.relative_displacement[i]
+            );
+            assert!(
+                (expected.relative_velocity[i] - actual.relative_velocity[i]).abs()
+                    < 1e-3 * peak_vel,
+                "index: {}, expected: {}, actual: {}",
+                i,
+                expected.relative_velocity[i],
+                actual.relative_velocity[i]
+            );
         }
     }
 }
@@ -0,0 +1,501 @@
+use crate::dynamic::sdof::{NigamJenningsCoefficients, SdofResponse, nigam_jennings};
+use crate::vector::Vector;
+use std::f64::consts::PI;
+
+/// 応答スペクトルを表す構造体
+///
+/// 擬似加速度応答スペクトル `psa = omega² · sd` と擬似速度応答スペクトル `psv = omega · sd` は、
+/// 減衰が小さい場合はそれぞれ `sa`, `sv` とほぼ一致するが、減衰定数が大きくなるほど乖離する。
+/// 特に `psv` と `sv` は長周期域で大きく異なる。
+///
+/// # フィールド
+///
+/// * `periods` - 固有周期
+/// * `sa` - 絶対加速度応答スペクトル
+/// * `sv` - 相対速度応答スペクトル
+/// * `sd` - 相対変位応答スペクトル
+/// * `psa` - 擬似加速度応答スペクトル
+/// * `psv` - 擬似速度応答スペクトル
+pub struct ResponseSpectrum {
+    pub periods: Vector<f64>,
+    pub sa: Vector<f64>,
+    pub sv: Vector<f64>,
+    pub sd: Vector<f64>,
+    pub psa: Vector<f64>,
+    pub psv: Vector<f64>,
+}
+
+/// 3軸（トリパータイト）応答スペクトル図を描くためのデータを表す構造体
+///
+/// `sv` と `sa` は `sd` から擬似スペクトルの関係 `Sv = omega · Sd`、`Sa = omega² · Sd`
+/// （`omega = 2π / T`）で求めた値であり、3つの量が同じ図上で整合する。
+///
+/// # フィールド
+///
+/// * `periods` - 固有周期
+/// * `frequencies` - 固有振動数（`1 / T`）
+/// * `sd` - 相対変位応答スペクトル
+/// * `sv` - 擬似速度応答スペクトル
+/// * `sa` - 擬似加速度応答スペクトル
+pub struct TripartiteData {
+    pub periods: Vector<f64>,
+    pub frequencies: Vector<f64>,
+    pub sd: Vector<f64>,
+    pub sv: Vector<f64>,
+    pub sa: Vector<f64>,
+}
+
+impl ResponseSpectrum {
+    /// 3軸応答スペクトル図のデータに変換する
+    ///
+    /// # 戻り値
+    ///
+    /// 3軸応答スペクトル図のデータを表す `TripartiteData` 構造体
+    pub fn to_tripartite(&self) -> TripartiteData {
+        let n: usize = self.periods.len();
+        let mut frequencies: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            frequencies[i] = 1.0 / self.periods[i];
+        }
+        TripartiteData {
+            periods: self.periods.clone(),
+            frequencies,
+            sd: self.sd.clone(),
+            sv: self.psv.clone(),
+            sa: self.psa.clone(),
+        }
+    }
+}
+
+/// Nigam-Jennings法を用いて応答スペクトルを計算する関数
+///
+/// 各固有周期 `T` について固有円振動数 `omega = 2π / T` の1質点系の応答を計算し、
+/// 応答の絶対値の最大値をスペクトル値とする。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `periods` - 固有周期
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 応答スペクトルを表す `ResponseSpectrum` 構造体
+pub fn response_spectrum(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    periods: &Vector<f64>,
+    h: f64,
+) -> ResponseSpectrum {
+    let peaks: Vec<(f64, f64, f64)> = (0..periods.len())
+        .map(|i| spectral_peaks(y0_ddot, delta_t, periods[i], h))
+        .collect();
+    assemble(periods, &peaks)
+}
+
+/// 同じ固有周期と時間刻みで応答スペクトルを繰り返し計算するための計画を表す構造体
+///
+/// 各固有周期に対するNigam-Jennings法の漸化式の係数を事前に計算しておき、
+/// 複数の地震動に対して `apply` で応答スペクトルを計算する際に再利用する。
+/// 結果は `response_spectrum` と完全に一致する。
+///
+/// # フィールド
+///
+/// * `periods` - 固有周期
+/// * `coefficients` - 各固有周期の漸化式の係数
+pub struct SpectrumPlan {
+    periods: Vector<f64>,
+    coefficients: Vec<NigamJenningsCoefficients<f64>>,
+}
+
+impl SpectrumPlan {
+    /// 新しい計画を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `delta_t` - 時間刻み
+    /// * `periods` - 固有周期
+    /// * `h` - 減衰定数
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい計画
+    pub fn new(delta_t: f64, periods: &Vector<f64>, h: f64) -> Self {
+        let coefficients: Vec<NigamJenningsCoefficients<f64>> = (0..periods.len())
+            .map(|i| NigamJenningsCoefficients::new(delta_t, 2.0 * PI / periods[i], h))
+            .collect();
+        SpectrumPlan {
+            periods: periods.clone(),
+            coefficients,
+        }
+    }
+
+    /// 地震動の応答スペクトルを計算する
+    ///
+    /// # 引数
+    ///
+    /// * `y0_ddot` - 地動加速度（時間刻みは計画の作成時と同じとする）
+    ///
+    /// # 戻り値
+    ///
+    /// 応答スペクトルを表す `ResponseSpectrum` 構造体
+    pub fn apply(&self, y0_ddot: &Vector<f64>) -> ResponseSpectrum {
+        let peaks: Vec<(f64, f64, f64)> = self
+            .coefficients
+            .iter()
+            .map(|coefficients| {
+                let response: SdofResponse = coefficients.response(y0_ddot);
+                (
+                    response.absolute_acceleration.norm_inf(),
+                    response.relative_velocity.norm_inf(),
+                    response.relative_displacement.norm_inf(),
+                )
+            })
+            .collect();
+        assemble(&self.periods, &peaks)
+    }
+}
+
+/// 複数のスレッドで並列に応答スペクトルを計算する関数
+///
+/// 固有周期ごとの1質点系の応答計算を `rayon` で並列化する。
+/// 結果は `response_spectrum` と完全に一致する。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `periods` - 固有周期
+/// * `h` - 減衰定数
+///
+/// # 戻り値
+///
+/// 応答スペクトルを表す `ResponseSpectrum` 構造体
+#[cfg(feature = "parallel")]
+pub fn response_spectrum_parallel(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    periods: &Vector<f64>,
+    h: f64,
+) -> ResponseSpectrum {
+    use rayon::prelude::*;
+
+    let peaks: Vec<(f64, f64, f64)> = (0..periods.len())
+        .into_par_iter()
+        .map(|i| spectral_peaks(y0_ddot, delta_t, periods[i], h))
+        .collect();
+    assemble(periods, &peaks)
+}
+
+/// Housnerのスペクトル強さを計算する関数
+///
+/// 擬似速度応答スペクトルを周期 `t_lower` から `t_upper` まで積分した値
+/// `SI = ∫ psv(T) dT` を台形則で求める。Housnerの定義では 0.1 秒から 2.5 秒とする。
+/// スペクトルの周期範囲が積分区間を覆わない場合は、重なる範囲のみを積分する。
+///
+/// # 引数
+///
+/// * `spectrum` - 応答スペクトル（固有周期は昇順）
+/// * `t_lower` - 積分区間の下限周期
+/// * `t_upper` - 積分区間の上限周期
+///
+/// # 戻り値
+///
+/// スペクトル強さ
+///
+/// # パニック
+///
+/// `t_lower` が `t_upper` 以上の場合にパニックする
+pub fn housner_intensity(spectrum: &ResponseSpectrum, t_lower: f64, t_upper: f64) -> f64 {
+    assert!(
+        t_lower < t_upper,
+        "積分区間が不正です: {} から {}",
+        t_lower,
+        t_upper
+    );
+    let periods: &Vector<f64> = &spectrum.periods;
+    let psv: &Vector<f64> = &spectrum.psv;
+
+    let mut intensity: f64 = 0.0;
+    for i in 1..periods.len() {
+        let (t0, t1) = (periods[i - 1], periods[i]);
+        let a: f64 = t0.max(t_lower);
+        let b: f64 = t1.min(t_upper);
+        if a >= b {
+            continue;
+        }
+        // 区間内は線形補間した値を用いる
+        let slope: f64 = (psv[i] - psv[i - 1]) / (t1 - t0);
+        let va: f64 = psv[i - 1] + slope * (a - t0);
+        let vb: f64 = psv[i - 1] + slope * (b - t0);
+        intensity += 0.5 * (va + vb) * (b - a);
+    }
+    intensity
+}
+
+/// 地震動の応答スペクトルを目標スペクトルに適合させる倍率を計算する関数
+///
+/// 周期 `t_lower` から `t_upper` の範囲の各周期 `T_i` について、擬似加速度応答スペクトルの
+/// 対数の残差の二乗和 `Σ (ln Sa_target(T_i) - ln(α · Sa_record(T_i)))²` を最小化する倍率 `α` を求める。
+/// 最小化の条件から `ln α` は対数の差の平均 `(1 / N) Σ (ln Sa_target(T_i) - ln Sa_record(T_i))` となる。
+///
+/// # 引数
+///
+/// * `record_spectrum` - 地震動の応答スペクトル
+/// * `target` - 目標スペクトル（固有周期は `record_spectrum` と同じとする）
+/// * `t_lower` - 適合させる範囲の下限周期
+/// * `t_upper` - 適合させる範囲の上限周期
+///
+/// # 戻り値
+///
+/// 地震動に乗じる倍率
+///
+/// # パニック
+///
+/// 2つのスペクトルの固有周期が一致しない場合、範囲内に固有周期がない場合、
+/// または範囲内のスペクトル値が正でない場合にパニックする
+pub fn spectral_scale_factor(
+    record_spectrum: &ResponseSpectrum,
+    target: &ResponseSpectrum,
+    t_lower: f64,
+    t_upper: f64,
+) -> f64 {
+    assert!(
+        record_spectrum.periods.approx_eq(&target.periods, 0.0),
+        "2つのスペクトルの固有周期が一致しません"
+    );
+    let mut sum: f64 = 0.0;
+    let mut count: usize = 0;
+    for i in 0..record_spectrum.periods.len() {
+        let period: f64 = record_spectrum.periods[i];
+        if period < t_lower || period > t_upper {
+            continue;
+        }
+        let record: f64 = record_spectrum.psa[i];
+        let expected: f64 = target.psa[i];
+        assert!(
+            record > 0.0 && expected > 0.0,
+            "スペクトル値が正ではありません: 周期 {}",
+            period
+        );
+        sum += expected.ln() - record.ln();
+        count += 1;
+    }
+    assert!(count > 0, "範囲内に固有周期がありません");
+    (sum / count as f64).exp()
+}
+
+/// 1つの固有周期について応答の絶対値の最大値を計算する
+///
+/// # 戻り値
+///
+/// 絶対加速度、相対速度、相対変位の最大値のタプル
+fn spectral_peaks(y0_ddot: &Vector<f64>, delta_t: f64, period: f64, h: f64) -> (f64, f64, f64) {
+    let omega: f64 = 2.0 * PI / period;
+    let response: SdofResponse = nigam_jennings(y0_ddot, delta_t, omega, h);
+    (
+        response.absolute_acceleration.norm_inf(),
+        response.relative_velocity.norm_inf(),
+        response.relative_displacement.norm_inf(),
+    )
+}
+
+/// 各固有周期の応答の最大値から `ResponseSpectrum` 構造体を組み立てる
+fn assemble(periods: &Vector<f64>, peaks: &[(f64, f64, f64)]) -> ResponseSpectrum {
+    let n: usize = periods.len();
+
+    let mut sa: Vector<f64> = Vector::new(n);
+    let mut sv: Vector<f64> = Vector::new(n);
+    let mut sd: Vector<f64> = Vector::new(n);
+    let mut psa: Vector<f64> = Vector::new(n);
+    let mut psv: Vector<f64> = Vector::new(n);
+
+    for i in 0..n {
+        let omega: f64 = 2.0 * PI / periods[i];
+        (sa[i], sv[i], sd[i]) = peaks[i];
+        psa[i] = omega * omega * sd[i];
+        psv[i] = omega * sd[i];
+    }
+
+    ResponseSpectrum {
+        periods: periods.clone(),
+        sa,
+        sv,
+        sd,
+        psa,
+        psv,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// 単一周期の応答スペクトルが1質点系の応答の最大値と一致することを確認するテスト
+    fn test_response_spectrum_single_period() {
+        let delta_t: f64 = 0.01;
+        let mut y0_ddot: Vector<f64> = Vector::new(500);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin();
+        }
+        let period: f64 = 0.5;
+        let h: f64 = 0.05;
+        let spectrum = response_spectrum(&y0_ddot, delta_t, &Vector::from_vec(vec![period]), h);
+        let response = nigam_jennings(&y0_ddot, delta_t, 2.0 * PI / period, h);
+
+        let mut max_acc: f64 = 0.0;
+        let mut max_vel: f64 = 0.0;
+        let mut max_disp: f64 = 0.0;
+        for i in 0..y0_ddot.len() {
+            max_acc = max_acc.max(response.absolute_acceleration[i].abs());
+            max_vel = max_vel.max(response.relative_velocity[i].abs());
+            max_disp = max_disp.max(response.relative_displacement[i].abs());
+        }
+        assert_eq!(spectrum.periods[0], period);
+        assert_eq!(spectrum.sa[0], max_acc);
+        assert_eq!(spectrum.sv[0], max_vel);
+        assert_eq!(spectrum.sd[0], max_disp);
+    }
+
+    #[test]
+    /// 減衰が小さい場合に擬似加速度応答スペクトルが絶対加速度応答スペクトルとほぼ一致することを確認するテスト
+    fn test_pseudo_spectrum_low_damping() {
+        let delta_t: f64 = 0.01;
+        let mut y0_ddot: Vector<f64> = Vector::new(1000);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin() + 0.5 * (2.0 * PI * t / 0.3).sin();
+        }
+        let periods = Vector::from_vec(vec![0.2, 0.5, 1.0]);
+        let h: f64 = 0.01;
+        let spectrum = response_spectrum(&y0_ddot, delta_t, &periods, h);
+        for i in 0..periods.len() {
+            let omega: f64 = 2.0 * PI / periods[i];
+            assert!((spectrum.psv[i] - omega * spectrum.sd[i]).abs() < 1e-12);
+            assert!(
+                (spectrum.psa[i] - spectrum.sa[i]).abs() / spectrum.sa[i] < 0.05,
+                "period: {}, psa: {}, sa: {}",
+                periods[i],
+                spectrum.psa[i],
+                spectrum.sa[i]
+            );
+        }
+    }
+
+    #[test]
+    /// 3軸応答スペクトル図のデータの振動数軸が周期軸の逆数であることを確認するテスト
+    fn test_to_tripartite() {
+        let delta_t: f64 = 0.01;
+        let mut y0_ddot: Vector<f64> = Vector::new(500);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin();
+        }
+        let periods = Vector::from_vec(vec![0.2, 0.5, 1.0, 2.0]);
+        let spectrum = response_spectrum(&y0_ddot, delta_t, &periods, 0.05);
+        let tripartite = spectrum.to_tripartite();
+        for i in 0..periods.len() {
+            let omega: f64 = 2.0 * PI / periods[i];
+            assert_eq!(tripartite.periods[i], periods[i]);
+            assert!((tripartite.frequencies[i] * periods[i] - 1.0).abs() < 1e-12);
+            assert_eq!(tripartite.sd[i], spectrum.sd[i]);
+            assert!((tripartite.sv[i] - omega * tripartite.sd[i]).abs() < 1e-12);
+            assert!((tripartite.sa[i] - omega * omega * tripartite.sd[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    /// 擬似速度応答スペクトルが一定の場合のスペクトル強さのテスト
+    fn test_housner_intensity_flat() {
+        let periods = Vector::linspace(0.05, 3.0, 60);
+        let n: usize = periods.len();
+        let mut psv: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            psv[i] = 10.0;
+        }
+        let spectrum = ResponseSpectrum {
+            periods,
+            sa: Vector::new(n),
+            sv: Vector::new(n),
+            sd: Vector::new(n),
+            psa: Vector::new(n),
+            psv,
+        };
+        let si: f64 = housner_intensity(&spectrum, 0.1, 2.5);
+        assert!((si - 10.0 * 2.4).abs() < 1e-12, "si: {}", si);
+
+        // スペクトルの周期範囲外は積分しない
+        let si: f64 = housner_intensity(&spectrum, 2.0, 4.0);
+        assert!((si - 10.0 * 1.0).abs() < 1e-12, "si: {}", si);
+    }
+
+    #[test]
+    /// 2倍した地震動の応答スペクトルを元のスペクトルに適合させる倍率が0.5となることを確認するテスト
+    fn test_spectral_scale_factor() {
+        let delta_t: f64 = 0.01;
+        let mut y0_ddot: Vector<f64> = Vector::new(1000);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin() + 0.5 * (2.0 * PI * t / 0.3).sin();
+        }
+        let periods = Vector::linspace(0.1, 3.0, 30);
+        let target = response_spectrum(&y0_ddot, delta_t, &periods, 0.05);
+        let record = response_spectrum(&y0_ddot.scale(2.0), delta_t, &periods, 0.05);
+        let factor: f64 = spectral_scale_factor(&record, &target, 0.2, 2.0);
+        assert!((factor - 0.5).abs() < 1e-9, "factor: {}", factor);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    /// 並列計算した応答スペクトルが逐次計算の結果と一致することを確認するテスト
+    fn test_response_spectrum_parallel() {
+        let delta_t: f64 = 0.01;
+        let mut y0_ddot: Vector<f64> = Vector::new(500);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = (2.0 * PI * t / 0.7).sin() * (-0.2 * t).exp();
+        }
+        let periods = Vector::linspace(0.1, 3.0, 30);
+        let serial = response_spectrum(&y0_ddot, delta_t, &periods, 0.05);
+        let parallel = response_spectrum_parallel(&y0_ddot, delta_t, &periods, 0.05);
+        for i in 0..periods.len() {
+            assert_eq!(serial.periods[i], parallel.periods[i]);
+            assert_eq!(serial.sa[i], parallel.sa[i]);
+            assert_eq!(serial.sv[i], parallel.sv[i]);
+            assert_eq!(serial.sd[i], parallel.sd[i]);
+            assert_eq!(serial.psa[i], parallel.psa[i]);
+            assert_eq!(serial.psv[i], parallel.psv[i]);
+        }
+    }
+
+    #[test]
+    /// 計画を用いた応答スペクトルが `response_spectrum` と一致することを確認するテスト
+    fn test_spectrum_plan_matches_direct() {
+        let delta_t: f64 = 0.01;
+        let periods = Vector::from_vec(vec![0.1, 0.3, 0.5, 1.0, 2.0]);
+        let h: f64 = 0.05;
+        let plan = SpectrumPlan::new(delta_t, &periods, h);
+
+        // 同じ計画を複数の地震動に適用する
+        for shift in [0.7, 0.3] {
+            let y0_ddot: Vector<f64> = Vector::from_fn(800, |i| {
+                let t: f64 = i as f64 * delta_t;
+                (2.0 * PI * t / shift).sin() * (-0.2 * t).exp()
+            });
+            let direct = response_spectrum(&y0_ddot, delta_t, &periods, h);
+            let planned = plan.apply(&y0_ddot);
+            assert_eq!(planned.periods.len(), periods.len());
+            for i in 0..periods.len() {
+                assert_eq!(planned.periods[i], direct.periods[i]);
+                assert_eq!(planned.sa[i], direct.sa[i]);
+                assert_eq!(planned.sv[i], direct.sv[i]);
+                assert_eq!(planned.sd[i], direct.sd[i]);
+                assert_eq!(planned.psa[i], direct.psa[i]);
+                assert_eq!(planned.psv[i], direct.psv[i]);
+            }
+        }
+    }
+}
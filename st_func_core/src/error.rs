@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// ベクトルや行列の次元が演算に適合しないことを表すエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DimensionError {
+    /// ベクトルのサイズが一致しない
+    VectorSizeMismatch { left: usize, right: usize },
+    /// 行列の行数・列数が演算に適合しない
+    MatrixShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DimensionError::VectorSizeMismatch { left, right } => {
+                write!(f, "ベクトルのサイズが一致しません: {} と {}", left, right)
+            }
+            DimensionError::MatrixShapeMismatch { left, right } => write!(
+                f,
+                "行列のサイズが適合しません: {}x{} と {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DimensionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dimension_error_display() {
+        let e = DimensionError::VectorSizeMismatch { left: 2, right: 3 };
+        assert_eq!(e.to_string(), "ベクトルのサイズが一致しません: 2 と 3");
+        let e = DimensionError::MatrixShapeMismatch {
+            left: (2, 3),
+            right: (2, 2),
+        };
+        assert_eq!(e.to_string(), "行列のサイズが適合しません: 2x3 と 2x2");
+    }
+}
@@ -0,0 +1,511 @@
+use crate::complex::Complex;
+use crate::vector::Vector;
+use crate::window::hann;
+use std::f64::consts::PI;
+
+/// 基数2の高速フーリエ変換（Cooley-Tukey法）を行う関数
+///
+/// `X_k = Σ x_n exp(-2πi kn / N)` を計算する。正規化は行わない。
+///
+/// # 引数
+///
+/// * `data` - 入力データ
+///
+/// # 戻り値
+///
+/// フーリエ変換の結果
+///
+/// # パニック
+///
+/// 入力データの長さが2のべき乗でない場合にパニックする
+pub fn fft(data: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n: usize = data.len();
+    assert!(n.is_power_of_two(), "データ長が2のべき乗ではありません");
+    let mut x: Vec<Complex<f64>> = data.to_vec();
+
+    // ビット反転による並べ替え
+    let mut j: usize = 0;
+    for i in 1..n {
+        let mut bit: usize = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            x.swap(i, j);
+        }
+    }
+
+    // バタフライ演算
+    let mut len: usize = 2;
+    while len <= n {
+        let w_len: Complex<f64> = Complex::from_angle(-2.0 * PI / len as f64);
+        for start in (0..n).step_by(len) {
+            let mut w: Complex<f64> = Complex::new(1.0, 0.0);
+            for k in 0..len / 2 {
+                let u: Complex<f64> = x[start + k];
+                let v: Complex<f64> = x[start + k + len / 2] * w;
+                x[start + k] = u + v;
+                x[start + k + len / 2] = u - v;
+                w = w * w_len;
+            }
+        }
+        len <<= 1;
+    }
+    x
+}
+
+/// 逆高速フーリエ変換を行う関数
+///
+/// `x_n = (1 / N) Σ X_k exp(2πi kn / N)` を計算する。
+/// 共役の関係 `x = conj(FFT(conj(X))) / N` を用いて [`fft`] により計算するため、`ifft(fft(x)) = x` となる。
+///
+/// # 引数
+///
+/// * `spectrum` - フーリエ変換の結果
+///
+/// # 戻り値
+///
+/// 逆フーリエ変換の結果
+///
+/// # パニック
+///
+/// 入力データの長さが2のべき乗でない場合にパニックする
+pub fn ifft(spectrum: &[Complex<f64>]) -> Vec<Complex<f64>> {
+    let n: usize = spectrum.len();
+    let conjugated: Vec<Complex<f64>> = spectrum.iter().map(|x| x.conj()).collect();
+    fft(&conjugated)
+        .iter()
+        .map(|x| Complex::new(x.re / n as f64, -x.im / n as f64))
+        .collect()
+}
+
+/// フーリエ振幅スペクトルを計算する関数
+///
+/// 記録長以上の2のべき乗の長さ `N` まで0を補ってFFTを行い、
+/// 振動数 `k / (N delta_t)` （`k = 0, 1, ..., N/2`）におけるフーリエ振幅 `|X_k| delta_t` を返す。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 振動数とフーリエ振幅のタプル
+pub fn fourier_amplitude_spectrum(
+    signal: &Vector<f64>,
+    delta_t: f64,
+) -> (Vector<f64>, Vector<f64>) {
+    let n: usize = signal.len().max(1).next_power_of_two();
+    let mut data: Vec<Complex<f64>> = vec![Complex::default(); n];
+    for i in 0..signal.len() {
+        data[i] = Complex::new(signal[i], 0.0);
+    }
+    let spectrum: Vec<Complex<f64>> = fft(&data);
+
+    let m: usize = n / 2 + 1;
+    let mut frequency: Vector<f64> = Vector::new(m);
+    let mut amplitude: Vector<f64> = Vector::new(m);
+    for k in 0..m {
+        frequency[k] = k as f64 / (n as f64 * delta_t);
+        amplitude[k] = spectrum[k].norm() * delta_t;
+    }
+    (frequency, amplitude)
+}
+
+/// 入力と出力の記録から伝達関数の振幅を計算する関数
+///
+/// `|FFT(output) / FFT(input)|` を `fourier_amplitude_spectrum` で求めた振幅の比として計算する。
+/// 入力の振幅が最大振幅の `1e-12` 倍以下の振動数では0除算を避けるため0とする。
+/// 平滑化が必要な場合は、入力と出力の振幅をそれぞれ `parzen_smooth` で平滑化してから比をとる。
+///
+/// # 引数
+///
+/// * `input` - 入力の時刻歴
+/// * `output` - 出力の時刻歴
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 振動数と伝達関数の振幅のタプル
+///
+/// # パニック
+///
+/// 入力と出力のサイズが一致しない場合にパニックする
+pub fn transfer_function(
+    input: &Vector<f64>,
+    output: &Vector<f64>,
+    delta_t: f64,
+) -> (Vector<f64>, Vector<f64>) {
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "入力と出力のサイズが一致しません"
+    );
+    let (frequency, input_amplitude) = fourier_amplitude_spectrum(input, delta_t);
+    let (_, output_amplitude) = fourier_amplitude_spectrum(output, delta_t);
+
+    let threshold: f64 = 1e-12 * input_amplitude.norm_inf();
+    let mut ratio: Vector<f64> = Vector::new(frequency.len());
+    for k in 0..frequency.len() {
+        if input_amplitude[k] > threshold {
+            ratio[k] = output_amplitude[k] / input_amplitude[k];
+        }
+    }
+    (frequency, ratio)
+}
+
+/// Parzenウィンドウによりフーリエ振幅スペクトルを平滑化する関数
+///
+/// バンド幅 `b` のParzenウィンドウ
+///
+/// `W(f) = (3 / 4) u · [sin(π u f / 2) / (π u f / 2)]⁴`、`u = 280 / (151 b)`
+///
+/// を重みとする移動平均を振動数領域で計算する。ウィンドウは最初の零点 `|f| < 2 / u` で打ち切り、
+/// スペクトルの両端では範囲内の重みの和で正規化する。
+/// 振動数が等間隔であることを利用して各点の前後 `ceil((2 / u) / Δf)` 点のみを走査するため、
+/// 計算量はスペクトルの点数とウィンドウ内の点数の積に比例する。
+///
+/// # 引数
+///
+/// * `amplitude` - フーリエ振幅スペクトル
+/// * `freq` - 振動数（等間隔）
+/// * `bandwidth` - バンド幅 [Hz]
+///
+/// # 戻り値
+///
+/// 平滑化したフーリエ振幅スペクトル
+///
+/// # パニック
+///
+/// 振幅と振動数のサイズが一致しない場合、またはバンド幅が正でない場合にパニックする
+pub fn parzen_smooth(amplitude: &Vector<f64>, freq: &Vector<f64>, bandwidth: f64) -> Vector<f64> {
+    assert_eq!(
+        amplitude.len(),
+        freq.len(),
+        "振幅と振動数のサイズが一致しません"
+    );
+    assert!(bandwidth > 0.0, "バンド幅は正の値とします: {}", bandwidth);
+    let n: usize = amplitude.len();
+    let u: f64 = 280.0 / (151.0 * bandwidth);
+    let half_width: f64 = 2.0 / u;
+    // 振動数が等間隔であることを利用し、ウィンドウ内の点のみを走査する
+    let reach: usize = if n >= 2 && freq[1] > freq[0] {
+        ((half_width / (freq[1] - freq[0])).ceil() as usize).min(n)
+    } else {
+        n
+    };
+
+    let mut smoothed: Vector<f64> = Vector::new(n);
+    for k in 0..n {
+        let mut sum: f64 = 0.0;
+        let mut weight_sum: f64 = 0.0;
+        for j in k.saturating_sub(reach)..(k + reach + 1).min(n) {
+            let df: f64 = freq[j] - freq[k];
+            if df.abs() >= half_width {
+                continue;
+            }
+            let weight: f64 = parzen_window(df, u);
+            sum += weight * amplitude[j];
+            weight_sum += weight;
+        }
+        smoothed[k] = sum / weight_sum;
+    }
+    smoothed
+}
+
+/// Parzenウィンドウの値を計算する
+fn parzen_window(f: f64, u: f64) -> f64 {
+    let x: f64 = PI * u * f / 2.0;
+    if x == 0.0 {
+        return 0.75 * u;
+    }
+    0.75 * u * (x.sin() / x).powi(4)
+}
+
+/// FFTを用いて2つの数列の線形畳み込みを計算する関数
+///
+/// `c_k = Σ a_j b_(k-j)` （`k = 0, 1, ..., na + nb - 2`）を計算する。
+/// FFTによる積は周期 `N` の循環畳み込みとなるため、`N` を `na + nb - 1` 以上の2のべき乗とし、
+/// 両方の数列の後ろに0を補ってから変換する。これにより循環による折り返しが生じず、
+/// 先頭の `na + nb - 1` 個が線形畳み込みと一致する。
+/// 積のスペクトルは [`ifft`] で時間領域に戻す。
+///
+/// # 引数
+///
+/// * `a` - 1つ目の数列
+/// * `b` - 2つ目の数列
+///
+/// # 戻り値
+///
+/// 長さ `na + nb - 1` の畳み込みの結果。いずれかが空の場合は空のベクトル
+pub fn fft_convolve(a: &Vector<f64>, b: &Vector<f64>) -> Vector<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vector::new(0);
+    }
+    let m: usize = a.len() + b.len() - 1;
+    let n: usize = m.next_power_of_two();
+    let mut data_a: Vec<Complex<f64>> = vec![Complex::default(); n];
+    let mut data_b: Vec<Complex<f64>> = vec![Complex::default(); n];
+    for i in 0..a.len() {
+        data_a[i] = Complex::new(a[i], 0.0);
+    }
+    for i in 0..b.len() {
+        data_b[i] = Complex::new(b[i], 0.0);
+    }
+    let spectrum_a: Vec<Complex<f64>> = fft(&data_a);
+    let spectrum_b: Vec<Complex<f64>> = fft(&data_b);
+    let product: Vec<Complex<f64>> = (0..n).map(|k| spectrum_a[k] * spectrum_b[k]).collect();
+    let inverse: Vec<Complex<f64>> = ifft(&product);
+    Vector::from_fn(m, |i| inverse[i].re)
+}
+
+/// Welch法によりパワースペクトル密度を推定する関数
+///
+/// 時刻歴を長さ `segment_len` 、重なり `overlap` のセグメントに分割し、各セグメントに
+/// [`hann`] で求めたHann窓 `w_n` を乗じてピリオドグラムを求め、それらを平均する。
+/// セグメントは `segment_len` 以上の2のべき乗の長さ `N` まで0を補ってFFTを行い、
+/// 振動数 `k / (N delta_t)` （`k = 0, 1, ..., N/2`）における片側パワースペクトル密度
+/// `2 delta_t |X_k|² / Σ w_n²` を返す（`k = 0` と `k = N/2` では係数2を乗じない）。
+/// 記録の末尾でセグメントに満たない部分は用いない。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `delta_t` - 時間刻み
+/// * `segment_len` - セグメントの長さ
+/// * `overlap` - 隣り合うセグメントの重なりのサンプル数
+///
+/// # 戻り値
+///
+/// 振動数とパワースペクトル密度のタプル
+///
+/// # パニック
+///
+/// セグメントの長さが2未満または時刻歴より長い場合、重なりがセグメントの長さ以上の場合にパニックする
+pub fn welch_psd(
+    signal: &Vector<f64>,
+    delta_t: f64,
+    segment_len: usize,
+    overlap: usize,
+) -> (Vector<f64>, Vector<f64>) {
+    assert!(
+        segment_len >= 2 && segment_len <= signal.len(),
+        "セグメントの長さが不正です: {}",
+        segment_len
+    );
+    assert!(
+        overlap < segment_len,
+        "重なりがセグメントの長さ以上です: {}",
+        overlap
+    );
+    let window: Vector<f64> = hann(segment_len);
+    let window_power: f64 = window.dot(&window);
+
+    let n: usize = segment_len.next_power_of_two();
+    let m: usize = n / 2 + 1;
+    let step: usize = segment_len - overlap;
+    let segments: usize = (signal.len() - segment_len) / step + 1;
+    let mut psd: Vector<f64> = Vector::new(m);
+    for s in 0..segments {
+        let start: usize = s * step;
+        let mut data: Vec<Complex<f64>> = vec![Complex::default(); n];
+        for i in 0..segment_len {
+            data[i] = Complex::new(signal[start + i] * window[i], 0.0);
+        }
+        let spectrum: Vec<Complex<f64>> = fft(&data);
+        for k in 0..m {
+            psd[k] += spectrum[k].norm().powi(2);
+        }
+    }
+
+    let mut frequency: Vector<f64> = Vector::new(m);
+    for k in 0..m {
+        frequency[k] = k as f64 / (n as f64 * delta_t);
+        let one_sided: f64 = if k == 0 || k == n / 2 { 1.0 } else { 2.0 };
+        psd[k] *= one_sided * delta_t / (window_power * segments as f64);
+    }
+    (frequency, psd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    #[test]
+    /// FFTの結果が離散フーリエ変換の定義式と一致することを確認するテスト
+    fn test_fft_matches_dft() {
+        let data: Vec<Complex<f64>> = (0..8)
+            .map(|i| Complex::new((i as f64).sin(), (i as f64 * 0.5).cos()))
+            .collect();
+        let result = fft(&data);
+        for k in 0..8 {
+            let mut expected = Complex::new(0.0, 0.0);
+            for n in 0..8 {
+                expected =
+                    expected + data[n] * Complex::from_angle(-2.0 * PI * (k * n) as f64 / 8.0);
+            }
+            assert!((result[k] - expected).norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    /// 逆FFTにより元のデータに戻ることを確認するテスト
+    fn test_ifft_round_trip() {
+        let data: Vec<Complex<f64>> = (0..16)
+            .map(|i| Complex::new((i as f64 * 0.7).sin(), (i as f64 * 0.3).cos() - 0.5))
+            .collect();
+        let restored = ifft(&fft(&data));
+        assert_eq!(restored.len(), 16);
+        for i in 0..16 {
+            assert!((restored[i] - data[i]).norm() < 1e-12, "i: {}", i);
+        }
+    }
+
+    #[test]
+    /// 正弦波のフーリエ振幅スペクトルが正しい振動数でピークとなることを確認するテスト
+    fn test_fourier_amplitude_spectrum_sine() {
+        let delta_t: f64 = 0.01;
+        let n: usize = 1000; // 1024まで0を補う
+        let bin: usize = 10;
+        let f0: f64 = bin as f64 / (1024.0 * delta_t);
+        let mut signal: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            signal[i] = (2.0 * PI * f0 * i as f64 * delta_t).sin();
+        }
+        let (frequency, amplitude) = fourier_amplitude_spectrum(&signal, delta_t);
+        assert_eq!(frequency.len(), 513);
+        let mut peak: usize = 0;
+        for k in 0..amplitude.len() {
+            if amplitude[k] > amplitude[peak] {
+                peak = k;
+            }
+        }
+        assert_eq!(peak, bin);
+        assert!((frequency[peak] - f0).abs() < 1e-12);
+    }
+
+    #[test]
+    /// 出力が入力の定数倍の場合に伝達関数の振幅が一定となることを確認するテスト
+    fn test_transfer_function_scaled() {
+        let delta_t: f64 = 0.01;
+        let input: Vector<f64> = Vector::from_fn(512, |i| {
+            let t: f64 = i as f64 * delta_t;
+            (2.0 * PI * 1.3 * t).sin() + 0.3 * (2.0 * PI * 7.1 * t).cos() + 0.1
+        });
+        let output: Vector<f64> = input.scale(2.5);
+        let (frequency, ratio) = transfer_function(&input, &output, delta_t);
+        assert_eq!(frequency.len(), 257);
+        for k in 0..ratio.len() {
+            assert!(
+                (ratio[k] - 2.5).abs() < 1e-9,
+                "k: {}, ratio: {}",
+                k,
+                ratio[k]
+            );
+        }
+
+        // 入力が0の場合は0とする
+        let (_, ratio) = transfer_function(&Vector::new(8), &Vector::new(8), delta_t);
+        assert_eq!(ratio.norm_inf(), 0.0);
+    }
+
+    #[test]
+    /// 平滑化によりスパイク状のスペクトルの分散が小さくなり、総和がほぼ保たれることを確認するテスト
+    fn test_parzen_smooth_spiky() {
+        let n: usize = 201;
+        let df: f64 = 0.05;
+        let freq: Vector<f64> = Vector::from_fn(n, |k| k as f64 * df);
+        let amplitude: Vector<f64> = Vector::from_fn(n, |k| if k % 10 == 5 { 11.0 } else { 1.0 });
+        let smoothed = parzen_smooth(&amplitude, &freq, 0.4);
+
+        let variance = |v: &Vector<f64>| {
+            let mean: f64 = (0..n).map(|k| v[k]).sum::<f64>() / n as f64;
+            (0..n).map(|k| (v[k] - mean).powi(2)).sum::<f64>() / n as f64
+        };
+        assert!(variance(&smoothed) < 0.1 * variance(&amplitude));
+
+        let total: f64 = (0..n).map(|k| amplitude[k]).sum();
+        let smoothed_total: f64 = (0..n).map(|k| smoothed[k]).sum();
+        assert!(
+            (smoothed_total - total).abs() / total < 0.05,
+            "total: {}, smoothed: {}",
+            total,
+            smoothed_total
+        );
+    }
+
+    #[test]
+    /// 正弦波に白色雑音を加えた時刻歴のパワースペクトル密度が正弦波の振動数でピークとなることを確認するテスト
+    fn test_welch_psd_sine_plus_noise() {
+        let delta_t: f64 = 0.01;
+        let f0: f64 = 5.0;
+        let mut rng = Rng::new(7);
+        let signal: Vector<f64> = Vector::from_fn(4096, |i| {
+            (2.0 * PI * f0 * i as f64 * delta_t).sin() + 0.5 * rng.next_gaussian()
+        });
+        let (frequency, psd) = welch_psd(&signal, delta_t, 256, 128);
+        assert_eq!(frequency.len(), 129);
+        assert_eq!(psd.len(), 129);
+
+        let peak: usize = psd.argmax();
+        let df: f64 = frequency[1] - frequency[0];
+        assert!(
+            (frequency[peak] - f0).abs() <= df,
+            "peak: {}",
+            frequency[peak]
+        );
+
+        // 白色雑音の片側パワースペクトル密度は 2 σ² delta_t 程度となる
+        let noise_level: f64 = 2.0 * 0.25 * delta_t;
+        assert!(psd[peak] > 10.0 * noise_level);
+        let far: usize = (20.0 / df) as usize;
+        assert!(psd[far] < 3.0 * noise_level && psd[far] > noise_level / 3.0);
+    }
+
+    #[test]
+    /// ウィンドウ内のみを走査した結果が全点を走査した結果と一致することを確認するテスト
+    fn test_parzen_smooth_matches_full_scan() {
+        let n: usize = 300;
+        let freq: Vector<f64> = Vector::from_fn(n, |k| k as f64 * 0.1);
+        let amplitude: Vector<f64> = Vector::from_fn(n, |k| 1.0 + (k as f64 * 1.3).sin().abs());
+        let bandwidth: f64 = 0.8;
+        let smoothed = parzen_smooth(&amplitude, &freq, bandwidth);
+
+        let u: f64 = 280.0 / (151.0 * bandwidth);
+        for k in 0..n {
+            let mut sum: f64 = 0.0;
+            let mut weight_sum: f64 = 0.0;
+            for j in 0..n {
+                let df: f64 = freq[j] - freq[k];
+                if df.abs() < 2.0 / u {
+                    sum += parzen_window(df, u) * amplitude[j];
+                    weight_sum += parzen_window(df, u);
+                }
+            }
+            assert!((smoothed[k] - sum / weight_sum).abs() < 1e-12, "k: {}", k);
+        }
+    }
+
+    #[test]
+    /// FFTによる畳み込みが定義式による直接計算と一致することを確認するテスト
+    fn test_fft_convolve_matches_direct() {
+        let a = Vector::from_vec(vec![1.0, -2.0, 0.5, 3.0, 4.0]);
+        let b = Vector::from_vec(vec![0.5, 1.5, -1.0]);
+        let result = fft_convolve(&a, &b);
+        assert_eq!(result.len(), 7);
+        for k in 0..7 {
+            let mut expected: f64 = 0.0;
+            for j in 0..a.len() {
+                if k >= j && k - j < b.len() {
+                    expected += a[j] * b[k - j];
+                }
+            }
+            assert!((result[k] - expected).abs() < 1e-12, "k: {}", k);
+        }
+        assert_eq!(fft_convolve(&a, &Vector::new(0)).len(), 0);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::dynamic::sdof::SdofResponse;
+use crate::io::{invalid_data, parse_number};
+use crate::vector::Vector;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// CSVファイルから加速度時刻歴を読み込む関数
+///
+/// 先頭の `header_rows` 行を読み飛ばし、各行の `column` 列目（0始まり）の値を読み込む。
+/// 空行は無視する。
+///
+/// # 引数
+///
+/// * `path` - CSVファイルのパス
+/// * `column` - 読み込む列のインデックス
+/// * `header_rows` - 読み飛ばすヘッダーの行数
+///
+/// # 戻り値
+///
+/// 加速度時刻歴
+///
+/// # エラー
+///
+/// ファイルの読み込みに失敗した場合、指定された列が存在しない場合、
+/// または数値として解釈できない値が含まれる場合にエラーを返す
+pub fn read_acceleration_csv(
+    path: &Path,
+    column: usize,
+    header_rows: usize,
+) -> io::Result<Vector<f64>> {
+    let content = fs::read_to_string(path)?;
+    let mut data: Vec<f64> = Vec::new();
+    for (i, line) in content.lines().enumerate().skip(header_rows) {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let field = line
+            .split(',')
+            .nth(column)
+            .ok_or_else(|| invalid_data(format!("{}行目に{}列目が存在しません", i + 1, column)))?;
+        data.push(parse_number(field)?);
+    }
+    Ok(Vector::from_vec(data))
+}
+
+/// 1質点系の応答をCSVファイルに書き出す関数
+///
+/// 1行目をヘッダー `time,acceleration,velocity,displacement` とし、
+/// 以降の各行に時刻 `i · delta_t`、絶対応答加速度、相対応答速度、相対応答変位を書き出す。
+///
+/// # 引数
+///
+/// * `path` - CSVファイルのパス
+/// * `response` - 1質点系の応答
+/// * `delta_t` - 時間刻み
+///
+/// # エラー
+///
+/// ファイルの作成または書き込みに失敗した場合にエラーを返す
+pub fn write_response_csv(path: &Path, response: &SdofResponse, delta_t: f64) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "time,acceleration,velocity,displacement")?;
+    for i in 0..response.relative_displacement.len() {
+        writeln!(
+            writer,
+            "{},{},{},{}",
+            i as f64 * delta_t,
+            response.absolute_acceleration[i],
+            response.relative_velocity[i],
+            response.relative_displacement[i]
+        )?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join(name)
+    }
+
+    #[test]
+    fn test_read_acceleration_csv() {
+        let acc = read_acceleration_csv(&fixture("acceleration.csv"), 1, 2).unwrap();
+        assert_eq!(acc.len(), 4);
+        assert_eq!(acc[0], 0.0);
+        assert_eq!(acc[1], 1.5);
+        assert_eq!(acc[2], -2.25);
+        assert_eq!(acc[3], 3.0);
+    }
+
+    #[test]
+    fn test_read_acceleration_csv_malformed() {
+        let err = read_acceleration_csv(&fixture("acceleration_malformed.csv"), 1, 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("値を数値に変換できません"));
+    }
+
+    #[test]
+    fn test_write_response_csv() {
+        let response = SdofResponse {
+            time: Vector::from_vec(vec![0.0, 0.01]),
+            absolute_acceleration: Vector::from_vec(vec![1.5, -2.0]),
+            relative_velocity: Vector::from_vec(vec![0.25, 0.5]),
+            relative_displacement: Vector::from_vec(vec![-0.125, 0.0]),
+        };
+        let path = std::env::temp_dir().join(format!(
+            "st_func_core_test_write_response_{}.csv",
+            std::process::id()
+        ));
+        write_response_csv(&path, &response, 0.01).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let mut lines = content.lines();
+        assert_eq!(
+            lines.next(),
+            Some("time,acceleration,velocity,displacement")
+        );
+        assert_eq!(lines.next(), Some("0,1.5,0.25,-0.125"));
+
+        let acc = read_acceleration_csv(&path, 1, 1).unwrap();
+        assert_eq!(acc.len(), 2);
+        assert_eq!(acc[1], -2.0);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,115 @@
+use crate::io::{invalid_data, parse_number};
+use crate::vector::Vector;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// K-NET/KiK-net形式の強震記録を表す構造体
+///
+/// # フィールド
+///
+/// * `station_code` - 観測点コード
+/// * `sampling_frequency` - サンプリング振動数 [Hz]
+/// * `delta_t` - 時間刻み [s]
+/// * `scale_factor` - 記録値を加速度 [gal] に変換する係数
+/// * `max_acceleration` - ヘッダーに記載された最大加速度 [gal]
+/// * `acceleration` - 加速度時刻歴 [gal]
+pub struct KnetRecord {
+    pub station_code: String,
+    pub sampling_frequency: f64,
+    pub delta_t: f64,
+    pub scale_factor: f64,
+    pub max_acceleration: f64,
+    pub acceleration: Vector<f64>,
+}
+
+/// K-NET/KiK-net形式（ASCII）の強震記録を読み込む関数
+///
+/// `Memo.` の行までをヘッダーとして読み、`Sampling Freq(Hz)`、`Scale Factor`、`Max. Acc. (gal)` を取得する。
+/// 以降の行の空白区切りの整数値に `Scale Factor`（例: `3920(gal)/6182761`）を乗じて加速度 [gal] とする。
+/// 記録値に含まれるオフセットは除去しないため、必要に応じて `signal::baseline_correct` などで補正する。
+///
+/// # 引数
+///
+/// * `path` - 記録ファイルのパス
+///
+/// # 戻り値
+///
+/// 強震記録を表す `KnetRecord` 構造体
+///
+/// # エラー
+///
+/// ファイルの読み込みに失敗した場合、必要なヘッダー項目がない場合、
+/// または数値として解釈できない値が含まれる場合にエラーを返す
+pub fn read_knet(path: &Path) -> io::Result<KnetRecord> {
+    let content = fs::read_to_string(path)?;
+    let mut lines = content.lines();
+
+    let mut station_code: Option<String> = None;
+    let mut sampling_frequency: Option<f64> = None;
+    let mut scale_factor: Option<f64> = None;
+    let mut max_acceleration: Option<f64> = None;
+    for line in lines.by_ref() {
+        if let Some(value) = line.strip_prefix("Station Code") {
+            station_code = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Sampling Freq(Hz)") {
+            sampling_frequency = Some(parse_number(value.trim().trim_end_matches("Hz"))?);
+        } else if let Some(value) = line.strip_prefix("Scale Factor") {
+            let (numerator, denominator) = value.trim().split_once('/').ok_or_else(|| {
+                invalid_data(format!("Scale Factorを解釈できません: {}", value.trim()))
+            })?;
+            scale_factor = Some(
+                parse_number(numerator.trim_end_matches("(gal)"))? / parse_number(denominator)?,
+            );
+        } else if let Some(value) = line.strip_prefix("Max. Acc. (gal)") {
+            max_acceleration = Some(parse_number(value)?);
+        } else if line.starts_with("Memo.") {
+            break;
+        }
+    }
+
+    let missing = |name: &str| invalid_data(format!("ヘッダーに{}がありません", name));
+    let station_code: String = station_code.ok_or_else(|| missing("Station Code"))?;
+    let sampling_frequency: f64 = sampling_frequency.ok_or_else(|| missing("Sampling Freq(Hz)"))?;
+    let scale_factor: f64 = scale_factor.ok_or_else(|| missing("Scale Factor"))?;
+    let max_acceleration: f64 = max_acceleration.ok_or_else(|| missing("Max. Acc. (gal)"))?;
+
+    let mut data: Vec<f64> = Vec::new();
+    for line in lines {
+        for field in line.split_whitespace() {
+            data.push(parse_number(field)? * scale_factor);
+        }
+    }
+
+    Ok(KnetRecord {
+        station_code,
+        sampling_frequency,
+        delta_t: 1.0 / sampling_frequency,
+        scale_factor,
+        max_acceleration,
+        acceleration: Vector::from_vec(data),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_knet() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("knet_sample.NS");
+        let record = read_knet(&path).unwrap();
+        let factor: f64 = 3920.0 / 6182761.0;
+        assert_eq!(record.station_code, "MYG004");
+        assert_eq!(record.sampling_frequency, 100.0);
+        assert!((record.delta_t - 0.01).abs() < 1e-15);
+        assert!((record.scale_factor - factor).abs() < 1e-15);
+        assert_eq!(record.max_acceleration, 0.951);
+        assert_eq!(record.acceleration.len(), 10);
+        assert!((record.acceleration[1] + 120.0 * factor).abs() < 1e-12);
+        assert!((record.acceleration[4] - 1500.0 * factor).abs() < 1e-12);
+        assert!((record.acceleration[8] - 100.0 * factor).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,18 @@
+pub mod csv;
+pub mod knet;
+pub mod peer;
+
+use std::io;
+
+/// 文字列を数値に変換する
+pub(crate) fn parse_number(field: &str) -> io::Result<f64> {
+    field
+        .trim()
+        .parse()
+        .map_err(|_| invalid_data(format!("値を数値に変換できません: {}", field.trim())))
+}
+
+/// `InvalidData` のエラーを作成する
+pub(crate) fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,109 @@
+use crate::io::{invalid_data, parse_number};
+use crate::vector::Vector;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// PEER NGA形式（.AT2）の加速度記録を読み込む関数
+///
+/// `NPTS=..., DT=...` の行からデータ数と時間刻みを取得し、以降の行の空白区切りの値を読み込む。
+/// 値の単位はファイルに記載のとおり（通常は重力加速度 g）であり、変換は行わない。
+///
+/// # 引数
+///
+/// * `path` - 記録ファイルのパス
+///
+/// # 戻り値
+///
+/// 加速度時刻歴と時間刻みのタプル
+///
+/// # エラー
+///
+/// ファイルの読み込みに失敗した場合、`NPTS` と `DT` の行がない場合、
+/// 数値として解釈できない値が含まれる場合、またはデータ数が `NPTS` と一致しない場合にエラーを返す
+pub fn read_peer_at2(path: &Path) -> io::Result<(Vector<f64>, f64)> {
+    let content = fs::read_to_string(path)?;
+    let mut lines = content.lines();
+
+    let mut header: Option<(usize, f64)> = None;
+    for line in lines.by_ref() {
+        if line.contains("NPTS") && line.contains("DT") {
+            header = Some(parse_header(line)?);
+            break;
+        }
+    }
+    let (npts, delta_t) =
+        header.ok_or_else(|| invalid_data("NPTSとDTの行がありません".to_string()))?;
+
+    let mut data: Vec<f64> = Vec::with_capacity(npts);
+    for line in lines {
+        for field in line.split_whitespace() {
+            data.push(parse_number(field)?);
+        }
+    }
+    if data.len() != npts {
+        return Err(invalid_data(format!(
+            "データ数がNPTSと一致しません: {} (NPTS: {})",
+            data.len(),
+            npts
+        )));
+    }
+    Ok((Vector::from_vec(data), delta_t))
+}
+
+/// `NPTS=  3930, DT=   .0100 SEC` の形式の行からデータ数と時間刻みを取得する
+fn parse_header(line: &str) -> io::Result<(usize, f64)> {
+    let mut npts: Option<usize> = None;
+    let mut delta_t: Option<f64> = None;
+    for item in line.split(',') {
+        let Some((key, value)) = item.split_once('=') else {
+            continue;
+        };
+        let value: &str = value.trim().trim_end_matches("SEC").trim();
+        match key.trim() {
+            "NPTS" => {
+                npts =
+                    Some(value.parse().map_err(|_| {
+                        invalid_data(format!("NPTSを整数に変換できません: {}", value))
+                    })?)
+            }
+            "DT" => delta_t = Some(parse_number(value)?),
+            _ => {}
+        }
+    }
+    match (npts, delta_t) {
+        (Some(npts), Some(delta_t)) => Ok((npts, delta_t)),
+        _ => Err(invalid_data(format!("NPTSとDTを解釈できません: {}", line))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_peer_at2() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata")
+            .join("peer_sample.AT2");
+        let (acc, delta_t) = read_peer_at2(&path).unwrap();
+        assert_eq!(acc.len(), 7);
+        assert_eq!(delta_t, 0.005);
+        assert_eq!(acc[0], 0.001);
+        assert_eq!(acc[3], 0.0125);
+        assert_eq!(acc[6], 0.00075);
+    }
+
+    #[test]
+    fn test_parse_header() {
+        assert_eq!(
+            parse_header("NPTS=  3930, DT=   .0100 SEC").unwrap(),
+            (3930, 0.01)
+        );
+        assert_eq!(
+            parse_header("NPTS=3930, DT=.0050 SEC,").unwrap(),
+            (3930, 0.005)
+        );
+        assert!(parse_header("NPTS=3930").is_err());
+    }
+}
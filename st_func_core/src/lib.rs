@@ -1,6 +1,20 @@
+#![allow(clippy::needless_range_loop)]
+
 pub mod matrix;
 pub mod vector;
+pub mod banded;
+pub mod complex;
 pub mod dynamic;
+pub mod error;
+pub mod fft;
+pub mod io;
+pub mod num;
+pub mod numeric;
+pub mod random;
+pub mod signal;
+pub mod solver;
+pub mod sparse;
+pub mod window;
 
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
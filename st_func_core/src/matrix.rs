@@ -1,14 +1,50 @@
-use std::ops::{Add, Index, IndexMut, Mul, Sub};
+use crate::complex::Complex;
+use crate::error::DimensionError;
+use crate::num::{Float, One, Zero};
+use crate::vector::Vector;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub};
 
 /// 行列を表す構造体
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawMatrix<T>"))]
 pub struct Matrix<T> {
     rows: usize,
     cols: usize,
     data: Vec<Vec<T>>,
 }
 
-impl<T: Default + Clone> Matrix<T> {
+/// デシリアライズした検証前の行列
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<Vec<T>>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawMatrix<T>> for Matrix<T> {
+    type Error = String;
+
+    /// 行数・列数とデータの形状が一致することを確認して行列を作成する
+    fn try_from(raw: RawMatrix<T>) -> Result<Self, Self::Error> {
+        if raw.data.len() != raw.rows || raw.data.iter().any(|row| row.len() != raw.cols) {
+            return Err(format!(
+                "行列のサイズとデータの形状が一致しません: {}x{}",
+                raw.rows, raw.cols
+            ));
+        }
+        Ok(Matrix {
+            rows: raw.rows,
+            cols: raw.cols,
+            data: raw.data,
+        })
+    }
+}
+
+impl<T: Zero + Clone> Matrix<T> {
     /// 新しい行列を作成する
     ///
     /// # 引数
@@ -20,7 +56,7 @@ impl<T: Default + Clone> Matrix<T> {
     ///
     /// 新しい行列
     pub fn new(rows: usize, cols: usize) -> Self {
-        let data = vec![vec![T::default(); cols]; rows];
+        let data = vec![vec![T::zero(); cols]; rows];
         Matrix { rows, cols, data }
     }
 
@@ -69,16 +105,166 @@ impl<T: Default + Clone> Matrix<T> {
             data,
         }
     }
+
+    /// 関数から各要素を生成して行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `rows` - 行数
+    /// * `cols` - 列数
+    /// * `f` - 行と列のインデックスを受け取り要素を返す関数
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい行列
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(rows: usize, cols: usize, mut f: F) -> Self {
+        let data = (0..rows)
+            .map(|i| (0..cols).map(|j| f(i, j)).collect())
+            .collect();
+        Matrix { rows, cols, data }
+    }
+}
+
+impl<T: Zero + One + Clone> Matrix<T> {
+    /// 単位行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `size` - 行列のサイズ
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい単位行列
+    pub fn identity(size: usize) -> Self {
+        let mut m = Self::new_square(size);
+        for i in 0..size {
+            m[(i, i)] = T::one();
+        }
+        m
+    }
+}
+
+impl<T> Matrix<T> {
+    /// 行数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 行数
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 列数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 列数
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 行数と列数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 行数と列数のタプル
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 2つの行を入れ替える（基本変形）
+    ///
+    /// # 引数
+    ///
+    /// * `i` - 入れ替える行
+    /// * `j` - 入れ替える行
+    ///
+    /// # パニック
+    ///
+    /// 行のインデックスが範囲外の場合にパニックする
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        self.check_row(i);
+        self.check_row(j);
+        self.data.swap(i, j);
+    }
+
+    /// 行のインデックスが範囲内か確認する
+    fn check_row(&self, i: usize) {
+        assert!(
+            i < self.rows,
+            "行のインデックスが範囲外です: {} (行数: {})",
+            i,
+            self.rows
+        );
+    }
+}
+
+impl<T: Copy> Matrix<T> {
+    /// 各要素に関数を適用した行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `f` - 各要素に適用する関数
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `(i, j)` 要素が `f(self[(i, j)])` である行列
+    pub fn map<U>(&self, f: impl Fn(T) -> U) -> Matrix<U> {
+        let data = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|&x| f(x)).collect())
+            .collect();
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// 2つの行列の対応する要素に関数を適用した行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - もう一方の行列
+    /// * `f` - 対応する要素の組に適用する関数
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `(i, j)` 要素が `f(self[(i, j)], other[(i, j)])` である行列
+    ///
+    /// # パニック
+    ///
+    /// 行列のサイズが一致しない場合にパニックする
+    pub fn zip_map(&self, other: &Matrix<T>, f: impl Fn(T, T) -> T) -> Matrix<T> {
+        assert!(
+            self.shape() == other.shape(),
+            "行列のサイズが一致しません: {:?} と {:?}",
+            self.shape(),
+            other.shape()
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(a, b)| a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect())
+            .collect();
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data,
+        }
+    }
 }
 
-impl<T: Default + Copy> Matrix<T> {
+impl<T: Zero + Copy> Matrix<T> {
     /// 行列を転置する
     ///
     /// # 戻り値
     ///
     /// 転置された行列
     pub fn transpose(&self) -> Self {
-        let mut transposed_data = vec![vec![T::default(); self.rows]; self.cols];
+        let mut transposed_data = vec![vec![T::zero(); self.rows]; self.cols];
         for i in 0..self.rows {
             for j in 0..self.cols {
                 transposed_data[j][i] = self.data[i][j];
@@ -90,149 +276,1403 @@ impl<T: Default + Copy> Matrix<T> {
             data: transposed_data,
         }
     }
-}
 
-impl<T> Index<(usize, usize)> for Matrix<T> {
-    type Output = T;
+    /// 行を取り出す
+    ///
+    /// # 引数
+    ///
+    /// * `i` - 行のインデックス
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `i` 行の要素を並べたベクトル
+    ///
+    /// # パニック
+    ///
+    /// インデックスが行数以上の場合にパニックする
+    pub fn row(&self, i: usize) -> Vector<T> {
+        assert!(
+            i < self.rows,
+            "行のインデックスが範囲外です: {} (行数: {})",
+            i,
+            self.rows
+        );
+        Vector::from_vec(self.data[i].clone())
+    }
 
-    /// 行列の要素を取得する
+    /// 列を取り出す
     ///
     /// # 引数
     ///
-    /// * `index` - 行と列のインデックス
+    /// * `j` - 列のインデックス
     ///
     /// # 戻り値
     ///
-    /// 指定された位置の要素への参照
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.data[index.0][index.1]
+    /// 第 `j` 列の要素を並べたベクトル
+    ///
+    /// # パニック
+    ///
+    /// インデックスが列数以上の場合にパニックする
+    pub fn col(&self, j: usize) -> Vector<T> {
+        assert!(
+            j < self.cols,
+            "列のインデックスが範囲外です: {} (列数: {})",
+            j,
+            self.cols
+        );
+        let mut result = Vector::new(self.rows);
+        for i in 0..self.rows {
+            result[i] = self.data[i][j];
+        }
+        result
     }
-}
 
-impl<T> IndexMut<(usize, usize)> for Matrix<T> {
-    /// 行列の要素を変更する
+    /// 各行をベクトルとして順に返すイテレータを作成する
+    ///
+    /// # 戻り値
+    ///
+    /// 第0行から順に各行のベクトルを返すイテレータ
+    pub fn iter_rows(&self) -> impl Iterator<Item = Vector<T>> + '_ {
+        (0..self.rows).map(move |i| self.row(i))
+    }
+
+    /// 各列をベクトルとして順に返すイテレータを作成する
+    ///
+    /// # 戻り値
+    ///
+    /// 第0列から順に各列のベクトルを返すイテレータ
+    pub fn iter_cols(&self) -> impl Iterator<Item = Vector<T>> + '_ {
+        (0..self.cols).map(move |j| self.col(j))
+    }
+
+    /// 部分行列を取り出す
     ///
     /// # 引数
     ///
-    /// * `index` - 行と列のインデックス
+    /// * `r0` - 開始行（含む）
+    /// * `r1` - 終了行（含まない）
+    /// * `c0` - 開始列（含む）
+    /// * `c1` - 終了列（含まない）
     ///
     /// # 戻り値
     ///
-    /// 指定された位置の要素への可変参照
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.data[index.0][index.1]
+    /// 行 `r0..r1`、列 `c0..c1` の部分行列
+    ///
+    /// # パニック
+    ///
+    /// 範囲が行列の外にある場合、または開始が終了より大きい場合にパニックする
+    pub fn submatrix(&self, r0: usize, r1: usize, c0: usize, c1: usize) -> Matrix<T> {
+        assert!(
+            r0 <= r1 && r1 <= self.rows,
+            "行の範囲が不正です: {}..{} (行数: {})",
+            r0,
+            r1,
+            self.rows
+        );
+        assert!(
+            c0 <= c1 && c1 <= self.cols,
+            "列の範囲が不正です: {}..{} (列数: {})",
+            c0,
+            c1,
+            self.cols
+        );
+        let data = self.data[r0..r1]
+            .iter()
+            .map(|row| row[c0..c1].to_vec())
+            .collect();
+        Matrix {
+            rows: r1 - r0,
+            cols: c1 - c0,
+            data,
+        }
     }
-}
 
-impl<T> Add for Matrix<T>
-where
-    T: Add<Output = T> + Default + Copy,
-{
-    type Output = Matrix<T>;
+    /// 行列を横方向に連結する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 右側に連結する行列
+    ///
+    /// # 戻り値
+    ///
+    /// `[self, other]` の行列
+    ///
+    /// # パニック
+    ///
+    /// 行数が一致しない場合にパニックする
+    pub fn hstack(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.rows, other.rows,
+            "行数が一致しません: {} と {}",
+            self.rows, other.rows
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(other.data.iter())
+            .map(|(left, right)| left.iter().chain(right.iter()).copied().collect())
+            .collect();
+        Matrix {
+            rows: self.rows,
+            cols: self.cols + other.cols,
+            data,
+        }
+    }
 
-    /// 2つの行列を加算する
+    /// 行列を縦方向に連結する
     ///
     /// # 引数
     ///
-    /// * `other` - 加算する行列
+    /// * `other` - 下側に連結する行列
     ///
     /// # 戻り値
     ///
-    /// 加算結果の行列
+    /// `[self; other]` の行列
     ///
     /// # パニック
     ///
-    /// 行列のサイズが一致しない場合にパニックする
-    fn add(self, other: Matrix<T>) -> Matrix<T> {
-        assert!(self.rows == other.rows && self.cols == other.cols);
-        let mut result = Matrix::new(self.rows, self.cols);
+    /// 列数が一致しない場合にパニックする
+    pub fn vstack(&self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.cols, other.cols,
+            "列数が一致しません: {} と {}",
+            self.cols, other.cols
+        );
+        let data = self.data.iter().chain(other.data.iter()).cloned().collect();
+        Matrix {
+            rows: self.rows + other.rows,
+            cols: self.cols,
+            data,
+        }
+    }
+
+    /// 行列の成分を行優先で1次元に並べたベクトルを作成する
+    ///
+    /// `(i, j)` 成分はベクトルの第 `i * cols + j` 要素となる。`Vector::reshape` で元の行列に戻せる。
+    ///
+    /// # 戻り値
+    ///
+    /// サイズ `rows * cols` のベクトル
+    pub fn flatten(&self) -> Vector<T> {
+        Vector::from_vec(self.data.iter().flatten().copied().collect())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Zero + Copy,
+{
+    /// 行列のトレース（対角成分の和）を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 対角成分の和
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn trace(&self) -> T {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let mut result = T::zero();
         for i in 0..self.rows {
-            for j in 0..self.cols {
-                result[(i, j)] = self[(i, j)] + other[(i, j)];
-            }
+            result = result + self[(i, i)];
         }
         result
     }
 }
 
-impl<T> Sub for Matrix<T>
+impl<T> Matrix<T>
 where
-    T: Sub<Output = T> + Default + Copy,
+    T: Mul<Output = T> + Zero + Copy,
 {
-    type Output = Matrix<T>;
+    /// 行列をスカラー倍する
+    ///
+    /// # 引数
+    ///
+    /// * `factor` - 乗じるスカラー
+    ///
+    /// # 戻り値
+    ///
+    /// スカラー倍された行列
+    pub fn scale(&self, factor: T) -> Matrix<T> {
+        let mut result = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(i, j)] = self[(i, j)] * factor;
+            }
+        }
+        result
+    }
 
-    /// 2つの行列を減算する
+    /// 2つの行列の要素ごとの積（アダマール積）を計算する
     ///
     /// # 引数
     ///
-    /// * `other` - 減算する行列
+    /// * `other` - 掛け合わせる行列
     ///
     /// # 戻り値
     ///
-    /// 減算結果の行列
+    /// 要素ごとの積の行列
     ///
     /// # パニック
     ///
     /// 行列のサイズが一致しない場合にパニックする
-    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+    pub fn hadamard(&self, other: &Matrix<T>) -> Matrix<T> {
         assert!(self.rows == other.rows && self.cols == other.cols);
         let mut result = Matrix::new(self.rows, self.cols);
         for i in 0..self.rows {
             for j in 0..self.cols {
-                result[(i, j)] = self[(i, j)] - other[(i, j)];
+                result[(i, j)] = self[(i, j)] * other[(i, j)];
             }
         }
         result
     }
 }
 
-impl<T> Mul for Matrix<T>
+impl<T> Matrix<T>
 where
-    T: Mul<Output = T> + Add<Output = T> + Default + Copy,
+    T: Add<Output = T> + Mul<Output = T> + Copy,
 {
-    type Output = Matrix<T>;
-
-    /// 2つの行列を掛け算する
+    /// 行を定数倍する（基本変形）
     ///
     /// # 引数
     ///
-    /// * `other` - 掛け算する行列
+    /// * `i` - 対象の行
+    /// * `factor` - 乗じる係数
     ///
-    /// # 戻り値
+    /// # パニック
     ///
-    /// 掛け算結果の行列
+    /// 行のインデックスが範囲外の場合にパニックする
+    pub fn scale_row(&mut self, i: usize, factor: T) {
+        self.check_row(i);
+        for x in self.data[i].iter_mut() {
+            *x = *x * factor;
+        }
+    }
+
+    /// ある行の定数倍を別の行に加える（基本変形）
+    ///
+    /// `dst` 行に `src` 行の `factor` 倍を加える。
+    ///
+    /// # 引数
+    ///
+    /// * `dst` - 加えられる行
+    /// * `src` - 加える行
+    /// * `factor` - `src` 行に乗じる係数
     ///
     /// # パニック
     ///
-    /// 行列のサイズが適切でない場合にパニックする
-    fn mul(self, other: Matrix<T>) -> Matrix<T> {
-        assert!(self.cols == other.rows);
-        let mut result = Matrix::new(self.rows, other.cols);
-        for i in 0..self.rows {
-            for j in 0..other.cols {
-                for k in 0..self.cols {
-                    result[(i, j)] = result[(i, j)] + (self[(i, k)] * other[(k, j)]);
-                }
-            }
+    /// 行のインデックスが範囲外の場合にパニックする
+    pub fn add_scaled_row(&mut self, dst: usize, src: usize, factor: T) {
+        self.check_row(dst);
+        self.check_row(src);
+        for j in 0..self.cols {
+            self.data[dst][j] = self.data[dst][j] + self.data[src][j] * factor;
         }
-        result
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_matrix_creation() {
-        let m: Matrix<f64> = Matrix::new(2, 3);
-        assert_eq!(m[(0, 0)], 0.0);
-        assert_eq!(m[(1, 2)], 0.0);
+impl<T> Matrix<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    /// 2つの行列を加算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 加算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 加算結果の行列。サイズが一致しない場合は `DimensionError`
+    pub fn try_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(self.shape_mismatch(other));
+        }
+        let mut result = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(i, j)] = self[(i, j)] + other[(i, j)];
+            }
+        }
+        Ok(result)
     }
 
-    #[test]
-    fn test_matrix_addition() {
-        let m1 = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// 2つの行列を減算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 減算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 減算結果の行列。サイズが一致しない場合は `DimensionError`
+    pub fn try_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(self.shape_mismatch(other));
+        }
+        let mut result = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(i, j)] = self[(i, j)] - other[(i, j)];
+            }
+        }
+        Ok(result)
+    }
+
+    /// 2つの行列を掛け算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 掛け算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 掛け算結果の行列。左の行列の列数と右の行列の行数が一致しない場合は `DimensionError`
+    pub fn try_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, DimensionError> {
+        if self.cols != other.rows {
+            return Err(self.shape_mismatch(other));
+        }
+        let mut result = Matrix::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                for k in 0..self.cols {
+                    result[(i, j)] = result[(i, j)] + (self[(i, k)] * other[(k, j)]);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 2つの行列のサイズを表す `DimensionError` を作成する
+    fn shape_mismatch(&self, other: &Matrix<T>) -> DimensionError {
+        DimensionError::MatrixShapeMismatch {
+            left: (self.rows, self.cols),
+            right: (other.rows, other.cols),
+        }
+    }
+}
+
+/// 対称行列と判定する閾値
+const SYMMETRY_TOLERANCE: f64 = 1e-9;
+
+/// Jacobi法の収束判定に用いる非対角成分の相対閾値
+const JACOBI_TOLERANCE: f64 = 1e-12;
+
+/// Jacobi法の最大スイープ回数
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+impl<T: Float> Matrix<T> {
+    /// 特異行列と判定するピボットの閾値を計算する
+    ///
+    /// 型の計算機イプシロンに行列のサイズと成分の絶対値の最大値を乗じた値とし、
+    /// 行列のスケールや `f32`・`f64` の精度の違いによらず判定できるようにする。
+    fn singular_tolerance(&self) -> T {
+        let mut max = T::zero();
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let value = self.data[i][j].abs();
+                if value > max {
+                    max = value;
+                }
+            }
+        }
+        T::epsilon() * T::from_f64(self.rows.max(self.cols) as f64) * max
+    }
+
+    /// 部分ピボット選択付きLU分解により行列式を計算する
+    ///
+    /// ピボットの絶対値が `singular_tolerance` 以下となった場合は特異とみなし、0を返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 行列式
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn determinant(&self) -> T {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let tolerance = self.singular_tolerance();
+        let mut det = T::one();
+
+        for k in 0..n {
+            let mut pivot = k;
+            for i in (k + 1)..n {
+                if a[i][k].abs() > a[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            if a[pivot][k].abs() <= tolerance {
+                return T::zero();
+            }
+            if pivot != k {
+                // 行の交換ごとに符号を反転する
+                a.swap(k, pivot);
+                det = -det;
+            }
+            det *= a[k][k];
+            for i in (k + 1)..n {
+                let factor = a[i][k] / a[k][k];
+                for j in k..n {
+                    a[i][j] = a[i][j] - factor * a[k][j];
+                }
+            }
+        }
+        det
+    }
+
+    /// ガウス・ジョルダン法により逆行列を計算する
+    ///
+    /// ピボットの絶対値が `singular_tolerance` 以下となった場合は特異とみなす。
+    ///
+    /// # 戻り値
+    ///
+    /// 逆行列。行列が特異な場合は `None`
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let n = self.rows;
+        let tolerance = self.singular_tolerance();
+        let mut a = self.data.clone();
+        let mut inv = Matrix::identity(n);
+
+        for k in 0..n {
+            let mut pivot = k;
+            for i in (k + 1)..n {
+                if a[i][k].abs() > a[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            if a[pivot][k].abs() <= tolerance {
+                return None;
+            }
+            a.swap(k, pivot);
+            inv.data.swap(k, pivot);
+
+            // ピボット行を正規化する
+            let p = a[k][k];
+            for j in 0..n {
+                a[k][j] /= p;
+                inv[(k, j)] /= p;
+            }
+
+            // ピボット列の他の要素を消去する
+            for i in 0..n {
+                if i == k {
+                    continue;
+                }
+                let factor = a[i][k];
+                for j in 0..n {
+                    a[i][j] = a[i][j] - factor * a[k][j];
+                    inv[(i, j)] = inv[(i, j)] - factor * inv[(k, j)];
+                }
+            }
+        }
+        Some(inv)
+    }
+}
+
+impl Matrix<f64> {
+    /// ベクトルを対角成分とする対角行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `diagonal` - 対角成分
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい対角行列
+    pub fn diagonal(diagonal: &Vector<f64>) -> Self {
+        let mut m = Self::new_square(diagonal.len());
+        for i in 0..diagonal.len() {
+            m[(i, i)] = diagonal[i];
+        }
+        m
+    }
+
+    /// フロベニウスノルムを計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 全要素の二乗和の平方根
+    pub fn frobenius_norm(&self) -> f64 {
+        let mut sum = 0.0;
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                sum += self[(i, j)] * self[(i, j)];
+            }
+        }
+        sum.sqrt()
+    }
+
+    /// 1ノルムを計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 各列の要素の絶対値の和の最大値
+    pub fn norm_1(&self) -> f64 {
+        let mut max = 0.0;
+        for j in 0..self.cols {
+            let mut sum = 0.0;
+            for i in 0..self.rows {
+                sum += self[(i, j)].abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    /// 無限大ノルムを計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 各行の要素の絶対値の和の最大値
+    pub fn norm_inf(&self) -> f64 {
+        let mut max = 0.0;
+        for i in 0..self.rows {
+            let mut sum = 0.0;
+            for j in 0..self.cols {
+                sum += self[(i, j)].abs();
+            }
+            if sum > max {
+                max = sum;
+            }
+        }
+        max
+    }
+
+    /// 行列が対称かどうかを判定する
+    ///
+    /// # 引数
+    ///
+    /// * `tol` - `A[(i, j)]` と `A[(j, i)]` の差の許容値
+    ///
+    /// # 戻り値
+    ///
+    /// 正方行列で、すべての要素の組の差が許容値以下の場合は `true`
+    pub fn is_symmetric(&self, tol: f64) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+        for i in 0..self.rows {
+            for j in (i + 1)..self.cols {
+                if (self[(i, j)] - self[(j, i)]).abs() > tol {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// 2つの行列が許容値の範囲で等しいかどうかを判定する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 比較する行列
+    /// * `tol` - 要素ごとの差の絶対値の許容値
+    ///
+    /// # 戻り値
+    ///
+    /// 形状が一致し、すべての要素の差が許容値以下の場合は `true`
+    pub fn approx_eq(&self, other: &Matrix<f64>, tol: f64) -> bool {
+        if self.shape() != other.shape() {
+            return false;
+        }
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if (self[(i, j)] - other[(i, j)]).abs() > tol {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// 部分ピボット選択付きガウスの消去法で連立一次方程式 `A x = b` を解く
+    ///
+    /// ピボットの絶対値が `singular_tolerance` 以下となった場合は特異とみなす。
+    ///
+    /// # 引数
+    ///
+    /// * `b` - 右辺ベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 解ベクトル `x`
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合、右辺ベクトルのサイズが行数と一致しない場合、
+    /// または行列が特異な場合にパニックする
+    pub fn solve(&self, b: &Vector<f64>) -> Vector<f64> {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        assert!(self.rows == b.len());
+        let n = self.rows;
+        let tolerance = self.singular_tolerance();
+        let mut a = self.data.clone();
+        let mut x = b.clone();
+
+        // 前進消去
+        for k in 0..n {
+            let mut pivot = k;
+            for i in (k + 1)..n {
+                if a[i][k].abs() > a[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            assert!(a[pivot][k].abs() > tolerance, "行列が特異です");
+            if pivot != k {
+                a.swap(k, pivot);
+                let tmp = x[k];
+                x[k] = x[pivot];
+                x[pivot] = tmp;
+            }
+            for i in (k + 1)..n {
+                let factor = a[i][k] / a[k][k];
+                for j in k..n {
+                    a[i][j] -= factor * a[k][j];
+                }
+                x[i] -= factor * x[k];
+            }
+        }
+
+        // 後退代入
+        for k in (0..n).rev() {
+            for j in (k + 1)..n {
+                x[k] -= a[k][j] * x[j];
+            }
+            x[k] /= a[k][k];
+        }
+        x
+    }
+
+    /// 部分ピボット選択付きLU分解を行う
+    ///
+    /// `P A = L U` を満たす置換、単位下三角行列 `L`、上三角行列 `U` を求める。
+    /// 置換はベクトル `p` で表し、`P A` の第 `i` 行は `A` の第 `p[i]` 行に対応する。
+    /// ピボットの絶対値が `singular_tolerance` 以下となった場合は特異とみなして `U` の対角成分と
+    /// `L` のその列の乗数を0とし、その列の消去を行わない。このとき `solve_lu` はパニックする。
+    ///
+    /// # 戻り値
+    ///
+    /// 置換 `p`、下三角行列 `L`、上三角行列 `U` のタプル
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn lu(&self) -> (Vec<usize>, Matrix<f64>, Matrix<f64>) {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let n = self.rows;
+        let tolerance = self.singular_tolerance();
+        let mut a = self.data.clone();
+        let mut p: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let mut pivot = k;
+            for i in (k + 1)..n {
+                if a[i][k].abs() > a[pivot][k].abs() {
+                    pivot = i;
+                }
+            }
+            a.swap(k, pivot);
+            p.swap(k, pivot);
+            if a[k][k].abs() <= tolerance {
+                // 消去を行わない列の乗数は0とする
+                a[k][k] = 0.0;
+                for row in a.iter_mut().skip(k + 1) {
+                    row[k] = 0.0;
+                }
+                continue;
+            }
+            for i in (k + 1)..n {
+                // 乗数は下三角部分に格納する
+                a[i][k] /= a[k][k];
+                for j in (k + 1)..n {
+                    a[i][j] -= a[i][k] * a[k][j];
+                }
+            }
+        }
+
+        let mut l = Matrix::identity(n);
+        let mut u = Matrix::new_square(n);
+        for i in 0..n {
+            for j in 0..n {
+                if j < i {
+                    l[(i, j)] = a[i][j];
+                } else {
+                    u[(i, j)] = a[i][j];
+                }
+            }
+        }
+        (p, l, u)
+    }
+
+    /// LU分解の結果を用いて連立一次方程式 `A x = b` を解く
+    ///
+    /// 同じ係数行列に対して複数の右辺ベクトルを解く場合に、分解を再利用できる。
+    /// `U` の対角成分の絶対値が `U` の `singular_tolerance` 以下の場合は特異とみなす。
+    ///
+    /// # 引数
+    ///
+    /// * `p` - `lu` で得られた置換
+    /// * `l` - `lu` で得られた下三角行列
+    /// * `u` - `lu` で得られた上三角行列
+    /// * `b` - 右辺ベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 解ベクトル `x`
+    ///
+    /// # パニック
+    ///
+    /// 右辺ベクトルのサイズが一致しない場合、または係数行列が特異な場合にパニックする
+    pub fn solve_lu(p: &[usize], l: &Matrix<f64>, u: &Matrix<f64>, b: &Vector<f64>) -> Vector<f64> {
+        let n = p.len();
+        assert!(n == b.len());
+        let tolerance = u.singular_tolerance();
+
+        // 前進代入 L y = P b
+        let mut x: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            x[i] = b[p[i]];
+            for j in 0..i {
+                x[i] -= l[(i, j)] * x[j];
+            }
+        }
+
+        // 後退代入 U x = y
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                x[i] -= u[(i, j)] * x[j];
+            }
+            assert!(u[(i, i)].abs() > tolerance, "行列が特異です");
+            x[i] /= u[(i, i)];
+        }
+        x
+    }
+
+    /// 1ノルムに基づく条件数を計算する
+    ///
+    /// 条件数 `κ(A) = ‖A‖₁ · ‖A⁻¹‖₁` を逆行列から求める。値が大きいほど連立方程式の解が誤差の影響を受けやすい。
+    ///
+    /// # 戻り値
+    ///
+    /// 条件数。行列が特異な場合は無限大
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn condition_number(&self) -> f64 {
+        match self.inverse() {
+            Some(inv) => self.norm_1() * inv.norm_1(),
+            None => f64::INFINITY,
+        }
+    }
+
+    /// Householder変換によりQR分解を行う
+    ///
+    /// `A = Q R` を満たす `m×m` の直交行列 `Q` と `m×n` の上三角行列 `R` を求める。
+    /// 第 `k` 列の対角成分より下を消去する鏡映 `H_k = I - 2 v vᵀ` を順に左から掛け、
+    /// `R = H_n … H_1 A`、`Q = H_1 … H_n` とする。
+    ///
+    /// # 戻り値
+    ///
+    /// 直交行列 `Q` と上三角行列 `R` のタプル
+    ///
+    /// # パニック
+    ///
+    /// 行数が列数より小さい場合にパニックする
+    pub fn qr(&self) -> (Matrix<f64>, Matrix<f64>) {
+        let (m, n) = self.shape();
+        assert!(
+            m >= n,
+            "行数が列数より小さい行列はQR分解できません: {}×{}",
+            m,
+            n
+        );
+        let mut r = self.clone();
+        let mut q = Matrix::identity(m);
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            // 鏡映ベクトル v = x - alpha e_1 （桁落ちを避けるため alpha は x_0 と逆符号）
+            let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+            let norm_x: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm_x == 0.0 {
+                continue;
+            }
+            let alpha: f64 = if v[0] > 0.0 { -norm_x } else { norm_x };
+            v[0] -= alpha;
+            let norm_v: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            for x in v.iter_mut() {
+                *x /= norm_v;
+            }
+
+            // R ← H_k R
+            for j in k..n {
+                let mut dot: f64 = 0.0;
+                for i in k..m {
+                    dot += v[i - k] * r[(i, j)];
+                }
+                for i in k..m {
+                    r[(i, j)] -= 2.0 * v[i - k] * dot;
+                }
+            }
+            // Q ← Q H_k
+            for i in 0..m {
+                let mut dot: f64 = 0.0;
+                for j in k..m {
+                    dot += q[(i, j)] * v[j - k];
+                }
+                for j in k..m {
+                    q[(i, j)] -= 2.0 * dot * v[j - k];
+                }
+            }
+            // 消去した成分の丸め誤差を除く
+            r[(k, k)] = alpha;
+            for i in (k + 1)..m {
+                r[(i, k)] = 0.0;
+            }
+        }
+        (q, r)
+    }
+
+    /// 巡回Jacobi法により対称行列の固有値と固有ベクトルを求める
+    ///
+    /// 非対角成分の二乗和の平方根が、行列全体の大きさに対する閾値を下回るまで回転を繰り返す。
+    ///
+    /// # 戻り値
+    ///
+    /// 固有値と、それに対応する固有ベクトルを列にもつ行列。
+    /// 固有値は並べ替えず、収束後の対角成分の順に返す
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合、または対称でない場合にパニックする
+    pub fn jacobi_eigen(&self) -> (Vector<f64>, Matrix<f64>) {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let n = self.rows;
+        assert!(
+            self.is_symmetric(SYMMETRY_TOLERANCE * (1.0 + self.norm_inf())),
+            "行列が対称ではありません"
+        );
+        let mut a: Matrix<f64> = self.clone();
+        let mut v: Matrix<f64> = Matrix::identity(n);
+
+        // 収束判定は行列全体の大きさに対する相対値で行う
+        let mut scale: f64 = 0.0;
+        for i in 0..n {
+            for j in 0..n {
+                scale += a[(i, j)] * a[(i, j)];
+            }
+        }
+        let threshold: f64 = JACOBI_TOLERANCE * scale.sqrt();
+
+        for _ in 0..JACOBI_MAX_SWEEPS {
+            let mut off: f64 = 0.0;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    off += a[(p, q)] * a[(p, q)];
+                }
+            }
+            if off.sqrt() <= threshold {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[(p, q)].abs() < f64::MIN_POSITIVE {
+                        continue;
+                    }
+                    let theta: f64 = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+                    let t: f64 = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c: f64 = 1.0 / (t * t + 1.0).sqrt();
+                    let s: f64 = t * c;
+                    for k in 0..n {
+                        let akp: f64 = a[(k, p)];
+                        let akq: f64 = a[(k, q)];
+                        a[(k, p)] = c * akp - s * akq;
+                        a[(k, q)] = s * akp + c * akq;
+                    }
+                    for k in 0..n {
+                        let apk: f64 = a[(p, k)];
+                        let aqk: f64 = a[(q, k)];
+                        a[(p, k)] = c * apk - s * aqk;
+                        a[(q, k)] = s * apk + c * aqk;
+                    }
+                    for k in 0..n {
+                        let vkp: f64 = v[(k, p)];
+                        let vkq: f64 = v[(k, q)];
+                        v[(k, p)] = c * vkp - s * vkq;
+                        v[(k, q)] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut values: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            values[i] = a[(i, i)];
+        }
+        (values, v)
+    }
+
+    /// べき乗法により絶対値が最大の固有値と固有ベクトルを求める
+    ///
+    /// `x ← A x / ‖A x‖` を繰り返し、固有値はレイリー商 `xᵀ A x` で求める。
+    /// 残差 `‖A x - λ x‖` が許容値を下回るか、反復回数が上限に達した時点で終了する。
+    /// 初期ベクトルはすべての成分が等しいベクトルとするため、
+    /// これと直交する固有ベクトルの固有値には収束しない。
+    ///
+    /// # 引数
+    ///
+    /// * `max_iter` - 最大反復回数
+    /// * `tol` - 残差の許容値
+    ///
+    /// # 戻り値
+    ///
+    /// 固有値と、ノルムを1に正規化した固有ベクトルのタプル
+    ///
+    /// # パニック
+    ///
+    /// 行列が正方でない場合にパニックする
+    pub fn power_iteration(&self, max_iter: usize, tol: f64) -> (f64, Vector<f64>) {
+        assert!(self.rows == self.cols, "行列が正方ではありません");
+        let n = self.rows;
+        let mut x: Vector<f64> = Vector::from_vec(vec![1.0 / (n as f64).sqrt(); n]);
+        let mut lambda: f64 = 0.0;
+
+        for _ in 0..max_iter {
+            let y: Vector<f64> = self * &x;
+            lambda = x.dot(&y);
+            let residual: f64 = (y.clone() - x.scale(lambda)).norm();
+            if residual < tol {
+                break;
+            }
+            let norm: f64 = y.norm();
+            if norm == 0.0 {
+                break;
+            }
+            x = y.scale(1.0 / norm);
+        }
+        (lambda, x)
+    }
+
+    /// 行列をLaTeXの `bmatrix` 環境の文字列に変換する
+    ///
+    /// 各要素を小数点以下 `precision` 桁で出力し、列を ` & `、行を ` \\` と改行で区切る。
+    ///
+    /// # 引数
+    ///
+    /// * `precision` - 小数点以下の桁数
+    ///
+    /// # 戻り値
+    ///
+    /// `\begin{bmatrix}` から `\end{bmatrix}` までの文字列
+    pub fn to_latex(&self, precision: usize) -> String {
+        let rows: Vec<String> = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| format!("{:.*}", precision, x))
+                    .collect::<Vec<String>>()
+                    .join(" & ")
+            })
+            .collect();
+        let mut latex = String::from("\\begin{bmatrix}\n");
+        for (i, row) in rows.iter().enumerate() {
+            latex.push_str(row);
+            if i + 1 < rows.len() {
+                latex.push_str(" \\\\");
+            }
+            latex.push('\n');
+        }
+        latex.push_str("\\end{bmatrix}");
+        latex
+    }
+
+    /// 行列をMarkdownの表の文字列に変換する
+    ///
+    /// 見出し行には列のインデックスを出力し、各要素は小数点以下 `precision` 桁で右揃えとする。
+    ///
+    /// # 引数
+    ///
+    /// * `precision` - 小数点以下の桁数
+    ///
+    /// # 戻り値
+    ///
+    /// 見出し行と区切り行に続けて各行を1行ずつ並べた文字列
+    pub fn to_markdown_table(&self, precision: usize) -> String {
+        let header: Vec<String> = (0..self.cols).map(|j| j.to_string()).collect();
+        let mut table = format!("| {} |\n", header.join(" | "));
+        table.push_str(&format!("|{}\n", "---:|".repeat(self.cols)));
+        for row in self.data.iter() {
+            let cells: Vec<String> = row.iter().map(|x| format!("{:.*}", precision, x)).collect();
+            table.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        table
+    }
+}
+
+impl Matrix<Complex<f64>> {
+    /// 各成分を共役複素数とした行列を作成する
+    ///
+    /// # 戻り値
+    ///
+    /// 共役複素数を成分とする行列
+    pub fn conjugate(&self) -> Matrix<Complex<f64>> {
+        self.map(|x| x.conj())
+    }
+
+    /// 共役転置行列（エルミート転置）を作成する
+    ///
+    /// # 戻り値
+    ///
+    /// `(i, j)` 成分が元の行列の `(j, i)` 成分の共役複素数である行列
+    pub fn hermitian(&self) -> Matrix<Complex<f64>> {
+        self.conjugate().transpose()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    /// 行列の要素を取得する
+    ///
+    /// # 引数
+    ///
+    /// * `index` - 行と列のインデックス
+    ///
+    /// # 戻り値
+    ///
+    /// 指定された位置の要素への参照
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.data[index.0][index.1]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    /// 行列の要素を変更する
+    ///
+    /// # 引数
+    ///
+    /// * `index` - 行と列のインデックス
+    ///
+    /// # 戻り値
+    ///
+    /// 指定された位置の要素への可変参照
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.data[index.0][index.1]
+    }
+}
+
+impl<T> Add for Matrix<T>
+where
+    T: Add<Output = T> + Zero + Copy,
+{
+    type Output = Matrix<T>;
+
+    /// 2つの行列を加算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 加算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 加算結果の行列
+    ///
+    /// # パニック
+    ///
+    /// 行列のサイズが一致しない場合にパニックする
+    fn add(self, other: Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == other.rows && self.cols == other.cols);
+        let mut result = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(i, j)] = self[(i, j)] + other[(i, j)];
+            }
+        }
+        result
+    }
+}
+
+impl<T> AddAssign for Matrix<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    /// 行列を加算して自身を更新する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 加算する行列
+    ///
+    /// # パニック
+    ///
+    /// 行列のサイズが一致しない場合にパニックする
+    fn add_assign(&mut self, other: Matrix<T>) {
+        assert!(self.rows == other.rows && self.cols == other.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = self[(i, j)] + other[(i, j)];
+            }
+        }
+    }
+}
+
+impl<T> Sub for Matrix<T>
+where
+    T: Sub<Output = T> + Zero + Copy,
+{
+    type Output = Matrix<T>;
+
+    /// 2つの行列を減算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 減算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 減算結果の行列
+    ///
+    /// # パニック
+    ///
+    /// 行列のサイズが一致しない場合にパニックする
+    fn sub(self, other: Matrix<T>) -> Matrix<T> {
+        assert!(self.rows == other.rows && self.cols == other.cols);
+        let mut result = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(i, j)] = self[(i, j)] - other[(i, j)];
+            }
+        }
+        result
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Matrix<T>;
+
+    /// 行列の各要素の符号を反転する
+    ///
+    /// # 戻り値
+    ///
+    /// 符号を反転した行列
+    fn neg(mut self) -> Matrix<T> {
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                self[(i, j)] = -self[(i, j)];
+            }
+        }
+        self
+    }
+}
+
+impl<T> Mul for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    type Output = Matrix<T>;
+
+    /// 2つの行列を掛け算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 掛け算する行列
+    ///
+    /// # 戻り値
+    ///
+    /// 掛け算結果の行列
+    ///
+    /// # パニック
+    ///
+    /// 行列のサイズが適切でない場合にパニックする
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        assert!(self.cols == other.rows);
+        let mut result = Matrix::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for j in 0..other.cols {
+                for k in 0..self.cols {
+                    result[(i, j)] = result[(i, j)] + (self[(i, k)] * other[(k, j)]);
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Mul<Output = T> + Zero + Copy,
+{
+    type Output = Matrix<T>;
+
+    /// 行列をスカラー倍する
+    ///
+    /// # 引数
+    ///
+    /// * `factor` - 乗じるスカラー
+    ///
+    /// # 戻り値
+    ///
+    /// スカラー倍された行列
+    fn mul(self, factor: T) -> Matrix<T> {
+        self.scale(factor)
+    }
+}
+
+impl<T> Mul<Vector<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    type Output = Vector<T>;
+
+    /// 行列とベクトルを掛け算する
+    ///
+    /// # 引数
+    ///
+    /// * `vector` - 掛け算するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 掛け算結果のベクトル
+    ///
+    /// # パニック
+    ///
+    /// 行列の列数とベクトルのサイズが一致しない場合にパニックする
+    fn mul(self, vector: Vector<T>) -> Vector<T> {
+        &self * &vector
+    }
+}
+
+impl<T> Mul<&Vector<T>> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    type Output = Vector<T>;
+
+    /// 行列とベクトルを参照のまま掛け算する
+    ///
+    /// 反復法などで同じ行列を繰り返し掛ける場合に、行列の複製を避けられる。
+    ///
+    /// # 引数
+    ///
+    /// * `vector` - 掛け算するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 掛け算結果のベクトル
+    ///
+    /// # パニック
+    ///
+    /// 行列の列数とベクトルのサイズが一致しない場合にパニックする
+    fn mul(self, vector: &Vector<T>) -> Vector<T> {
+        assert!(self.cols == vector.len());
+        let mut result = Vector::new(self.rows);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                result[i] = result[i] + (self[(i, k)] * vector[k]);
+            }
+        }
+        result
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    /// 行列を整形して出力する
+    ///
+    /// 各行を1行ずつ出力し、列ごとに右揃えする。
+    /// 書式指定の精度（例: `{:.3}`）が各要素に適用される。
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatted: Vec<Vec<String>> = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|x| match f.precision() {
+                        Some(p) => format!("{:.*}", p, x),
+                        None => format!("{}", x),
+                    })
+                    .collect()
+            })
+            .collect();
+        let width = formatted
+            .iter()
+            .flatten()
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (i, row) in formatted.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, x) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{:>width$}", x, width = width)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl From<ndarray::Array2<f64>> for Matrix<f64> {
+    /// `ndarray` の2次元配列から行列へ変換する。
+    fn from(array: ndarray::Array2<f64>) -> Self {
+        let data: Vec<Vec<f64>> = array.outer_iter().map(|row| row.to_vec()).collect();
+        let (rows, cols) = array.dim();
+        Matrix { rows, cols, data }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl From<Matrix<f64>> for ndarray::Array2<f64> {
+    /// 行列から `ndarray` の2次元配列へ変換する。
+    fn from(matrix: Matrix<f64>) -> Self {
+        let data: Vec<f64> = matrix.data.into_iter().flatten().collect();
+        ndarray::Array2::from_shape_vec((matrix.rows, matrix.cols), data)
+            .expect("行列の形状と要素数が一致しません")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_creation() {
+        let m: Matrix<f64> = Matrix::new(2, 3);
+        assert_eq!(m[(0, 0)], 0.0);
+        assert_eq!(m[(1, 2)], 0.0);
+    }
+
+    #[test]
+    fn test_matrix_shape() {
+        let m: Matrix<f64> = Matrix::new(2, 3);
+        assert_eq!(m.rows(), 2);
+        assert_eq!(m.cols(), 3);
+        assert_eq!(m.shape(), (2, 3));
+    }
+
+    #[test]
+    fn test_matrix_addition() {
+        let m1 = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
         let m2 = Matrix::from_vec(vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
         let m3 = m1 + m2;
         assert_eq!(m3[(0, 0)], 6.0);
@@ -272,6 +1712,477 @@ mod tests {
         assert_eq!(m3[(1, 1)], 8.0);
     }
 
+    #[test]
+    fn test_matrix_vector_multiplication() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let v = Vector::from_vec(vec![5.0, 6.0]);
+        let r = m * v;
+        assert_eq!(r.len(), 2);
+        assert_eq!(r[0], 17.0);
+        assert_eq!(r[1], 39.0);
+    }
+
+    #[test]
+    fn test_matrix_identity() {
+        let m: Matrix<f64> = Matrix::identity(3);
+        for i in 0..3 {
+            for j in 0..3 {
+                if i == j {
+                    assert_eq!(m[(i, j)], 1.0);
+                } else {
+                    assert_eq!(m[(i, j)], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_diagonal() {
+        let m = Matrix::diagonal(&Vector::from_vec(vec![1.0, 2.0, 3.0]));
+        assert_eq!(m[(0, 0)], 1.0);
+        assert_eq!(m[(1, 1)], 2.0);
+        assert_eq!(m[(2, 2)], 3.0);
+        assert_eq!(m[(0, 1)], 0.0);
+        assert_eq!(m[(2, 0)], 0.0);
+    }
+
+    #[test]
+    fn test_matrix_norms() {
+        let m = Matrix::from_vec(vec![vec![1.0, -2.0], vec![-3.0, 4.0]]);
+        assert!((m.frobenius_norm() - 30.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(m.norm_1(), 6.0);
+        assert_eq!(m.norm_inf(), 7.0);
+    }
+
+    #[test]
+    fn test_matrix_solve() {
+        let k = Matrix::from_vec(vec![
+            vec![2.0, 1.0, -1.0],
+            vec![-3.0, -1.0, 2.0],
+            vec![-2.0, 1.0, 2.0],
+        ]);
+        let f = Vector::from_vec(vec![8.0, -11.0, -3.0]);
+        let u = k.solve(&f);
+        assert!((u[0] - 2.0).abs() < 1e-12);
+        assert!((u[1] - 3.0).abs() < 1e-12);
+        assert!((u[2] + 1.0).abs() < 1e-12);
+        let residual = k * u - f;
+        for i in 0..residual.len() {
+            assert!(residual[i].abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_solve_singular() {
+        let k = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        let f = Vector::from_vec(vec![1.0, 2.0]);
+        k.solve(&f);
+    }
+
+    #[test]
+    fn test_matrix_determinant() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert!((m.determinant() + 2.0).abs() < 1e-12);
+        let m = Matrix::from_vec(vec![vec![5.0]]);
+        assert_eq!(m.determinant(), 5.0);
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(m.determinant(), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "行列が正方ではありません")]
+    fn test_matrix_determinant_non_square() {
+        let m: Matrix<f64> = Matrix::new(2, 3);
+        m.determinant();
+    }
+
+    #[test]
+    fn test_matrix_inverse() {
+        let m = Matrix::from_vec(vec![
+            vec![4.0, 7.0, 2.0],
+            vec![3.0, 6.0, 1.0],
+            vec![2.0, 5.0, 3.0],
+        ]);
+        let inv = m.inverse().unwrap();
+        let product = m * inv;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[(i, j)] - expected).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_inverse_singular() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(m.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrix_scaled_system_not_singular() {
+        // 一様に縮小した条件の良い行列は、すべての解法で特異と判定しない
+        let base = Matrix::from_vec(vec![
+            vec![4.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 2.0],
+        ]);
+        let x_expected = Vector::from_vec(vec![1.0, -2.0, 3.0]);
+        for scale in [1e-14, 1.0, 1e14] {
+            let a = base.clone() * scale;
+            let b = a.clone() * x_expected.clone();
+            assert!(
+                a.solve(&b).approx_eq(&x_expected, 1e-10),
+                "scale: {}",
+                scale
+            );
+            let (p, l, u) = a.lu();
+            assert!(
+                Matrix::solve_lu(&p, &l, &u, &b).approx_eq(&x_expected, 1e-10),
+                "scale: {}",
+                scale
+            );
+            let inv = a.inverse().unwrap();
+            assert!((inv.clone() * b).approx_eq(&x_expected, 1e-10));
+            let det: f64 = a.determinant();
+            assert!((det / scale.powi(3) - 18.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    /// 特異行列のLU分解で `L U = P A` が成り立ち、消去しない列の乗数が0となることを確認するテスト
+    fn test_matrix_lu_singular() {
+        let matrices = [
+            Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]),
+            Matrix::from_vec(vec![
+                vec![1.0, 0.0, 0.0],
+                vec![0.0, 1e-18, 1.0],
+                vec![0.0, 2e-18, 3.0],
+            ]),
+        ];
+        for a in matrices.iter() {
+            let n = a.rows();
+            let (p, l, u) = a.lu();
+            let lu = l.clone() * u.clone();
+            for i in 0..n {
+                for j in 0..n {
+                    assert!((lu[(i, j)] - a[(p[i], j)]).abs() < 1e-12);
+                }
+                assert_eq!(l[(i, i)], 1.0);
+            }
+        }
+        let (_, _, u) = matrices[0].lu();
+        assert_eq!(u[(1, 1)], 0.0);
+        let (_, l, u) = matrices[1].lu();
+        assert_eq!(u[(1, 1)], 0.0);
+        assert_eq!(l[(2, 1)], 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "行列が特異です")]
+    fn test_matrix_solve_lu_near_singular() {
+        let a = Matrix::from_vec(vec![vec![1.0, 1.0], vec![1.0, 1.0 + f64::EPSILON]]);
+        let (p, l, u) = a.lu();
+        assert_eq!(u[(1, 1)], 0.0);
+        Matrix::solve_lu(&p, &l, &u, &Vector::from_vec(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_matrix_determinant_inverse_f32() {
+        let m: Matrix<f32> = Matrix::from_vec(vec![
+            vec![4.0, 7.0, 2.0],
+            vec![3.0, 6.0, 1.0],
+            vec![2.0, 5.0, 3.0],
+        ]);
+        assert!((m.determinant() - 9.0).abs() < 1e-4);
+        let inv = m.inverse().unwrap();
+        let product = m * inv;
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected: f32 = if i == j { 1.0 } else { 0.0 };
+                assert!((product[(i, j)] - expected).abs() < 1e-5);
+            }
+        }
+
+        let singular: Matrix<f32> = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singular.determinant(), 0.0);
+        assert!(singular.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrix_singular_tolerance_scales() {
+        // 成分が小さくても正則な行列は特異と判定しない
+        let small: Matrix<f64> = Matrix::identity(3) * 1e-14;
+        assert!((small.determinant() - 1e-42).abs() < 1e-54);
+        let inv = small.inverse().unwrap();
+        assert!((inv[(1, 1)] - 1e14).abs() < 1e2);
+
+        // 丸め誤差程度のピボットは型の精度に応じて特異と判定する
+        let near: Matrix<f64> =
+            Matrix::from_vec(vec![vec![1.0, 1.0], vec![1.0, 1.0 + f64::EPSILON]]);
+        assert!(near.inverse().is_none());
+        let near: Matrix<f32> =
+            Matrix::from_vec(vec![vec![1.0, 1.0], vec![1.0, 1.0 + f32::EPSILON]]);
+        assert!(near.inverse().is_none());
+        assert_eq!(near.determinant(), 0.0);
+    }
+
+    #[test]
+    fn test_matrix_jacobi_eigen_diagonal() {
+        let m = Matrix::diagonal(&Vector::from_vec(vec![3.0, 1.0, 2.0]));
+        let (values, vectors) = m.jacobi_eigen();
+        assert_eq!(values[0], 3.0);
+        assert_eq!(values[1], 1.0);
+        assert_eq!(values[2], 2.0);
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert_eq!(vectors[(i, j)], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_jacobi_eigen_symmetric() {
+        // [[2, 1], [1, 2]] の固有値は 1 と 3
+        let m = Matrix::from_vec(vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        let (values, vectors) = m.jacobi_eigen();
+        let mut sorted = [values[0], values[1]];
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        assert!((sorted[0] - 1.0).abs() < 1e-12);
+        assert!((sorted[1] - 3.0).abs() < 1e-12);
+        // A v = λ v を満たすことを確認する
+        for j in 0..2 {
+            for i in 0..2 {
+                let av = m[(i, 0)] * vectors[(0, j)] + m[(i, 1)] * vectors[(1, j)];
+                assert!((av - values[j] * vectors[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "行列が対称ではありません")]
+    fn test_matrix_jacobi_eigen_non_symmetric() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        m.jacobi_eigen();
+    }
+
+    #[test]
+    fn test_matrix_mul_vector_by_reference() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+        let v = Vector::from_vec(vec![1.0, -1.0]);
+        let result = &m * &v;
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0], -1.0);
+        assert_eq!(result[2], -1.0);
+        assert!(result.approx_eq(&(m * v), 0.0));
+    }
+
+    #[test]
+    fn test_matrix_display() {
+        let m = Matrix::from_vec(vec![vec![1.0, -2.5], vec![10.0, 4.0]]);
+        assert_eq!(format!("{}", m), "[   1, -2.5]\n[  10,    4]");
+        assert_eq!(format!("{:.2}", m), "[ 1.00, -2.50]\n[10.00,  4.00]");
+    }
+
+    #[test]
+    fn test_matrix_to_latex() {
+        let m = Matrix::from_vec(vec![vec![1.0, -2.5], vec![10.0, 4.0], vec![0.3, 0.0]]);
+        let latex = m.to_latex(2);
+        assert!(latex.starts_with("\\begin{bmatrix}"));
+        assert!(latex.ends_with("\\end{bmatrix}"));
+        assert_eq!(latex.matches("\\\\").count(), 2);
+        assert_eq!(
+            latex,
+            "\\begin{bmatrix}\n1.00 & -2.50 \\\\\n10.00 & 4.00 \\\\\n0.30 & 0.00\n\\end{bmatrix}"
+        );
+    }
+
+    #[test]
+    fn test_matrix_to_markdown_table() {
+        let m = Matrix::from_vec(vec![vec![1.0, -2.5], vec![10.0, 4.0]]);
+        assert_eq!(
+            m.to_markdown_table(1),
+            "| 0 | 1 |\n|---:|---:|\n| 1.0 | -2.5 |\n| 10.0 | 4.0 |\n"
+        );
+    }
+
+    #[test]
+    fn test_matrix_complex_mul() {
+        let c = Complex::new;
+        let a = Matrix::from_vec(vec![
+            vec![c(1.0, 1.0), c(0.0, 2.0)],
+            vec![c(3.0, 0.0), c(1.0, -1.0)],
+        ]);
+        let b = Matrix::from_vec(vec![
+            vec![c(2.0, 0.0), c(0.0, -1.0)],
+            vec![c(1.0, 1.0), c(4.0, 0.0)],
+        ]);
+        let product = a.clone() * b;
+        assert_eq!(product[(0, 0)], c(0.0, 4.0));
+        assert_eq!(product[(0, 1)], c(1.0, 7.0));
+        assert_eq!(product[(1, 0)], c(8.0, 0.0));
+        assert_eq!(product[(1, 1)], c(4.0, -7.0));
+
+        let identity: Matrix<Complex<f64>> = Matrix::identity(2);
+        assert_eq!((a.clone() * identity)[(1, 1)], a[(1, 1)]);
+    }
+
+    #[test]
+    fn test_matrix_hermitian() {
+        let c = Complex::new;
+        let a = Matrix::from_vec(vec![
+            vec![c(1.0, 1.0), c(0.0, 2.0), c(5.0, 0.0)],
+            vec![c(3.0, 0.0), c(1.0, -1.0), c(2.0, 3.0)],
+        ]);
+        assert_eq!(a.conjugate()[(0, 1)], c(0.0, -2.0));
+        let h = a.hermitian();
+        assert_eq!(h.shape(), (3, 2));
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(h[(j, i)], a[(i, j)].conj());
+            }
+        }
+        // A Aᴴ はエルミート行列であり、対角成分は実数となる
+        let g = a.clone() * h;
+        assert_eq!(g[(0, 1)], g[(1, 0)].conj());
+        assert_eq!(g[(0, 0)].im, 0.0);
+    }
+
+    #[test]
+    fn test_matrix_try_operations() {
+        let m1 = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let m2 = Matrix::from_vec(vec![vec![2.0, 0.0], vec![1.0, 2.0]]);
+        let sum = m1.try_add(&m2).unwrap();
+        assert_eq!(sum[(0, 0)], 3.0);
+        assert_eq!(sum[(1, 1)], 6.0);
+        let diff = m1.try_sub(&m2).unwrap();
+        assert_eq!(diff[(0, 0)], -1.0);
+        assert_eq!(diff[(1, 0)], 2.0);
+        let product = m1.try_mul(&m2).unwrap();
+        assert_eq!(product[(0, 0)], 4.0);
+        assert_eq!(product[(1, 1)], 8.0);
+    }
+
+    #[test]
+    fn test_matrix_try_operations_mismatch() {
+        let m1: Matrix<f64> = Matrix::new(2, 3);
+        let m2: Matrix<f64> = Matrix::new(2, 2);
+        let expected = DimensionError::MatrixShapeMismatch {
+            left: (2, 3),
+            right: (2, 2),
+        };
+        assert_eq!(m1.try_add(&m2).unwrap_err(), expected);
+        assert_eq!(m1.try_sub(&m2).unwrap_err(), expected);
+        assert_eq!(m1.try_mul(&m2).unwrap_err(), expected);
+        assert!(m2.try_mul(&m1).is_ok());
+    }
+
+    #[test]
+    fn test_matrix_row_col() {
+        let m = Matrix::from_vec(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ]);
+        let r = m.row(1);
+        assert_eq!(r.len(), 3);
+        assert_eq!(r[0], 4.0);
+        assert_eq!(r[2], 6.0);
+        let c = m.col(2);
+        assert_eq!(c[0], 3.0);
+        assert_eq!(c[1], 6.0);
+        assert_eq!(c[2], 9.0);
+    }
+
+    #[test]
+    fn test_matrix_submatrix() {
+        let m = Matrix::from_vec(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ]);
+        let sub = m.submatrix(1, 3, 0, 2);
+        assert_eq!(sub[(0, 0)], 4.0);
+        assert_eq!(sub[(0, 1)], 5.0);
+        assert_eq!(sub[(1, 0)], 7.0);
+        assert_eq!(sub[(1, 1)], 8.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "行のインデックスが範囲外です")]
+    fn test_matrix_row_out_of_range() {
+        let m: Matrix<f64> = Matrix::new(2, 2);
+        m.row(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "列の範囲が不正です")]
+    fn test_matrix_submatrix_out_of_range() {
+        let m: Matrix<f64> = Matrix::new(3, 3);
+        m.submatrix(0, 2, 1, 4);
+    }
+
+    #[test]
+    fn test_matrix_trace() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert_eq!(m.trace(), 5.0);
+    }
+
+    #[test]
+    fn test_matrix_is_symmetric() {
+        let symmetric = Matrix::from_vec(vec![vec![2.0, 1.0], vec![1.0 + 1e-12, 3.0]]);
+        assert!(symmetric.is_symmetric(1e-9));
+        assert!(!symmetric.is_symmetric(0.0));
+        let non_symmetric = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        assert!(!non_symmetric.is_symmetric(1e-9));
+        let non_square: Matrix<f64> = Matrix::new(2, 3);
+        assert!(!non_square.is_symmetric(1e-9));
+    }
+
+    #[test]
+    fn test_matrix_hadamard() {
+        let m1 = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let m2 = Matrix::from_vec(vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
+        let m3 = m1.hadamard(&m2);
+        assert_eq!(m3[(0, 0)], 5.0);
+        assert_eq!(m3[(0, 1)], 12.0);
+        assert_eq!(m3[(1, 0)], 21.0);
+        assert_eq!(m3[(1, 1)], 32.0);
+    }
+
+    #[test]
+    fn test_matrix_lu() {
+        let a = Matrix::from_vec(vec![
+            vec![2.0, -1.0, 0.5, 3.0],
+            vec![4.0, 1.0, -2.0, 1.0],
+            vec![-1.0, 3.0, 1.0, 0.0],
+            vec![0.5, 2.0, 4.0, -1.5],
+        ]);
+        let (p, l, u) = a.lu();
+        let lu = l.clone() * u.clone();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((lu[(i, j)] - a[(p[i], j)]).abs() < 1e-12);
+                if j > i {
+                    assert_eq!(l[(i, j)], 0.0);
+                }
+                if j < i {
+                    assert_eq!(u[(i, j)], 0.0);
+                }
+            }
+            assert_eq!(l[(i, i)], 1.0);
+        }
+
+        let b = Vector::from_vec(vec![1.0, 2.0, 3.0, 4.0]);
+        let x = Matrix::solve_lu(&p, &l, &u, &b);
+        let expected = a.solve(&b);
+        for i in 0..4 {
+            assert!((x[i] - expected[i]).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_matrix_transpose() {
         let m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
@@ -291,4 +2202,325 @@ mod tests {
         assert_eq!(m[(1, 0)], 3.0);
         assert_eq!(m[(1, 1)], 4.0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_matrix_serde_round_trip() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let json = serde_json::to_string(&m).unwrap();
+        let restored: Matrix<f64> = serde_json::from_str(&json).unwrap();
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(restored[(i, j)], m[(i, j)]);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_matrix_serde_rejects_inconsistent_shape() {
+        let result = serde_json::from_str::<Matrix<f64>>(r#"{"rows":3,"cols":3,"data":[[1.0]]}"#);
+        let message = result.unwrap_err().to_string();
+        assert!(
+            message.contains("行列のサイズとデータの形状が一致しません"),
+            "{}",
+            message
+        );
+        let ragged = r#"{"rows":2,"cols":2,"data":[[1.0,2.0],[3.0]]}"#;
+        assert!(serde_json::from_str::<Matrix<f64>>(ragged).is_err());
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_matrix_ndarray_round_trip() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let array: ndarray::Array2<f64> = m.clone().into();
+        assert_eq!(array, ndarray::arr2(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+        let restored: Matrix<f64> = array.into();
+        assert_eq!(restored.shape(), (2, 3));
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(restored[(i, j)], m[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_from_fn() {
+        let m = Matrix::from_fn(3, 3, |i, j| i * 10 + j);
+        assert_eq!(m.shape(), (3, 3));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_eq!(m[(i, j)], i * 10 + j);
+            }
+        }
+
+        // ヒルベルト行列
+        let hilbert = Matrix::from_fn(2, 2, |i, j| 1.0 / (i + j + 1) as f64);
+        assert_eq!(hilbert[(1, 1)], 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_matrix_hstack() {
+        let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::from_vec(vec![vec![5], vec![6]]);
+        let c = a.hstack(&b);
+        assert_eq!(c.shape(), (2, 3));
+        assert_eq!(c[(0, 0)], 1);
+        assert_eq!(c[(0, 2)], 5);
+        assert_eq!(c[(1, 1)], 4);
+        assert_eq!(c[(1, 2)], 6);
+    }
+
+    #[test]
+    fn test_matrix_vstack() {
+        let a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+        let b = Matrix::from_vec(vec![vec![5, 6]]);
+        let c = a.vstack(&b);
+        assert_eq!(c.shape(), (3, 2));
+        assert_eq!(c[(1, 0)], 3);
+        assert_eq!(c[(2, 0)], 5);
+        assert_eq!(c[(2, 1)], 6);
+    }
+
+    #[test]
+    fn test_matrix_flatten_reshape_round_trip() {
+        let m = Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let flat = m.flatten();
+        assert_eq!(flat.len(), 6);
+        for k in 0..6 {
+            assert_eq!(flat[k], k as i32 + 1);
+        }
+        let reshaped = flat.reshape(2, 3);
+        assert_eq!(reshaped.shape(), (2, 3));
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(reshaped[(i, j)], m[(i, j)]);
+            }
+        }
+        assert_eq!(flat.reshape(3, 2)[(1, 0)], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "行数が一致しません")]
+    fn test_matrix_hstack_row_mismatch() {
+        let a: Matrix<f64> = Matrix::new(2, 2);
+        let b: Matrix<f64> = Matrix::new(3, 1);
+        a.hstack(&b);
+    }
+
+    #[test]
+    fn test_matrix_elementary_row_operations() {
+        // [0 2; 1 1] を段階的に単位行列へ簡約する
+        let mut m = Matrix::from_vec(vec![vec![0.0, 2.0], vec![1.0, 1.0]]);
+        m.swap_rows(0, 1);
+        assert_eq!(m[(0, 0)], 1.0);
+        assert_eq!(m[(1, 1)], 2.0);
+        m.scale_row(1, 0.5);
+        assert_eq!(m[(1, 0)], 0.0);
+        assert_eq!(m[(1, 1)], 1.0);
+        m.add_scaled_row(0, 1, -1.0);
+        assert_eq!(m[(0, 0)], 1.0);
+        assert_eq!(m[(0, 1)], 0.0);
+        assert_eq!(m[(1, 0)], 0.0);
+        assert_eq!(m[(1, 1)], 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "行のインデックスが範囲外です")]
+    fn test_matrix_swap_rows_out_of_range() {
+        let mut m: Matrix<f64> = Matrix::new(2, 2);
+        m.swap_rows(0, 2);
+    }
+
+    #[test]
+    fn test_matrix_approx_eq() {
+        let a = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b = Matrix::from_vec(vec![vec![1.0, 2.0 + 1e-10], vec![3.0, 4.0]]);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-12));
+
+        // 形状が異なる場合は等しくない
+        let c: Matrix<f64> = Matrix::new(2, 3);
+        assert!(!a.approx_eq(&c, 1e9));
+    }
+
+    #[test]
+    fn test_matrix_qr() {
+        let a = Matrix::from_vec(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+            vec![1.0, 2.0, 3.0],
+        ]);
+        let (q, r) = a.qr();
+        assert_eq!(q.shape(), (4, 4));
+        assert_eq!(r.shape(), (4, 3));
+        assert!((q.clone() * r.clone()).approx_eq(&a, 1e-10));
+        assert!((q.transpose() * q).approx_eq(&Matrix::identity(4), 1e-12));
+        for i in 0..4 {
+            for j in 0..i.min(3) {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_power_iteration() {
+        // [[2, 1, 0], [1, 3, 1], [0, 1, 4]] の最大固有値は 3 + √3
+        let m = Matrix::from_vec(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![1.0, 3.0, 1.0],
+            vec![0.0, 1.0, 4.0],
+        ]);
+        let (value, vector) = m.power_iteration(1000, 1e-12);
+        let expected: f64 = 3.0 + 3.0_f64.sqrt();
+        assert!((value - expected).abs() < 1e-10, "value: {}", value);
+        assert!((vector.norm() - 1.0).abs() < 1e-12);
+        // 固有ベクトルは (1, 1 + √3, 2 + √3) に比例する
+        let v3: f64 = 3.0_f64.sqrt();
+        let exact = Vector::from_vec(vec![1.0, 1.0 + v3, 2.0 + v3]);
+        let exact = exact.scale(1.0 / exact.norm());
+        assert!(vector.approx_eq(&exact, 1e-10));
+    }
+
+    #[test]
+    fn test_matrix_scalar_mul() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0]]) * 2.0;
+        assert_eq!(m.shape(), (1, 2));
+        assert_eq!(m[(0, 0)], 2.0);
+        assert_eq!(m[(0, 1)], 4.0);
+
+        let m = Matrix::from_vec(vec![vec![1, -2], vec![3, 4]]).scale(3);
+        assert_eq!(m[(0, 1)], -6);
+        assert_eq!(m[(1, 0)], 9);
+    }
+
+    #[test]
+    fn test_matrix_add_assign() {
+        let mut m = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        m += Matrix::from_vec(vec![vec![0.5, -1.0], vec![1.0, 0.0]]);
+        assert_eq!(m[(0, 0)], 1.5);
+        assert_eq!(m[(0, 1)], 1.0);
+        assert_eq!(m[(1, 0)], 4.0);
+        assert_eq!(m[(1, 1)], 4.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_add_assign_shape_mismatch() {
+        let mut m: Matrix<f64> = Matrix::new(2, 2);
+        m += Matrix::new(2, 3);
+    }
+
+    #[test]
+    fn test_matrix_neg() {
+        let m = -Matrix::from_vec(vec![vec![1.0, -2.0], vec![0.0, 4.0]]);
+        assert_eq!(m[(0, 0)], -1.0);
+        assert_eq!(m[(0, 1)], 2.0);
+        assert_eq!(m[(1, 1)], -4.0);
+    }
+
+    /// 小数点以下3桁の固定小数点数（`Default` を実装しない数値型）
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Fixed(i64);
+
+    impl Zero for Fixed {
+        fn zero() -> Self {
+            Fixed(0)
+        }
+    }
+
+    impl One for Fixed {
+        fn one() -> Self {
+            Fixed(1000)
+        }
+    }
+
+    impl Add for Fixed {
+        type Output = Fixed;
+        fn add(self, other: Fixed) -> Fixed {
+            Fixed(self.0 + other.0)
+        }
+    }
+
+    impl Sub for Fixed {
+        type Output = Fixed;
+        fn sub(self, other: Fixed) -> Fixed {
+            Fixed(self.0 - other.0)
+        }
+    }
+
+    impl Mul for Fixed {
+        type Output = Fixed;
+        fn mul(self, other: Fixed) -> Fixed {
+            Fixed(self.0 * other.0 / 1000)
+        }
+    }
+
+    #[test]
+    fn test_matrix_custom_numeric_type() {
+        let zero: Matrix<Fixed> = Matrix::new(2, 2);
+        assert_eq!(zero[(1, 0)], Fixed(0));
+
+        let a = Matrix::from_vec(vec![
+            vec![Fixed(1500), Fixed(-2000)],
+            vec![Fixed(250), Fixed(4000)],
+        ]);
+        let product = Matrix::identity(2) * a.clone();
+        for i in 0..2 {
+            for j in 0..2 {
+                assert_eq!(product[(i, j)], a[(i, j)]);
+            }
+        }
+        assert_eq!(a.trace(), Fixed(5500));
+        let x = Vector::from_vec(vec![Fixed(2000), Fixed(1000)]);
+        assert_eq!((a * x)[0], Fixed(1000));
+    }
+
+    #[test]
+    fn test_matrix_iter_rows_cols() {
+        let m = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let row_sums: Vec<f64> = m.iter_rows().map(|row| row.sum()).collect();
+        assert_eq!(row_sums, vec![6.0, 15.0]);
+        let col_sums: Vec<f64> = m.iter_cols().map(|col| col.sum()).collect();
+        assert_eq!(col_sums, vec![5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    fn test_matrix_map() {
+        let m = Matrix::from_vec(vec![vec![1.0, -2.0], vec![3.0, 0.5]]);
+        let doubled = m.map(|x| x * 2.0);
+        assert!(doubled.approx_eq(
+            &Matrix::from_vec(vec![vec![2.0, -4.0], vec![6.0, 1.0]]),
+            0.0
+        ));
+        let signs: Matrix<bool> = m.map(|x| x > 0.0);
+        assert!(signs[(0, 0)]);
+        assert!(!signs[(0, 1)]);
+    }
+
+    #[test]
+    fn test_matrix_zip_map() {
+        let a = Matrix::from_vec(vec![vec![1.0, 5.0], vec![-3.0, 2.0]]);
+        let b = Matrix::from_vec(vec![vec![4.0, 2.0], vec![-1.0, 2.0]]);
+        let max = a.zip_map(&b, f64::max);
+        assert!(max.approx_eq(
+            &Matrix::from_vec(vec![vec![4.0, 5.0], vec![-1.0, 2.0]]),
+            0.0
+        ));
+    }
+
+    #[test]
+    fn test_matrix_condition_number() {
+        let identity: Matrix<f64> = Matrix::identity(3);
+        assert_eq!(identity.condition_number(), 1.0);
+
+        // ほぼ特異な行列の条件数は大きい
+        let near_singular = Matrix::from_vec(vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-8]]);
+        assert!(near_singular.condition_number() > 1e8);
+
+        let singular = Matrix::from_vec(vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert_eq!(singular.condition_number(), f64::INFINITY);
+    }
 }
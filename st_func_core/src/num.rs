@@ -0,0 +1,141 @@
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 加法の単位元（0）をもつ型を表すトレイト
+///
+/// `Default` の値が0であるとは限らない型でも、ゼロ初期化や和の計算を正しく行えるようにする。
+pub trait Zero {
+    /// 0を返す
+    fn zero() -> Self;
+}
+
+/// 乗法の単位元（1）をもつ型を表すトレイト
+pub trait One {
+    /// 1を返す
+    fn one() -> Self;
+}
+
+macro_rules! impl_zero_one {
+    ($($t:ident),*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    0 as $t
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    1 as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+/// 浮動小数点数を表すトレイト
+///
+/// 計算過程を把握できるように外部ライブラリを使わず、`f32` と `f64` に共通して必要な演算のみを定義する。
+pub trait Float:
+    Copy
+    + Debug
+    + Zero
+    + One
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// `f64` の値から変換する
+    fn from_f64(value: f64) -> Self;
+
+    /// 計算機イプシロンを返す
+    fn epsilon() -> Self;
+
+    /// 絶対値を返す
+    fn abs(self) -> Self;
+
+    /// 平方根を返す
+    fn sqrt(self) -> Self;
+
+    /// 指数関数の値を返す
+    fn exp(self) -> Self;
+
+    /// 正弦関数の値を返す
+    fn sin(self) -> Self;
+
+    /// 余弦関数の値を返す
+    fn cos(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hypot<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+
+    #[test]
+    fn test_float_generic() {
+        assert_eq!(hypot(3.0_f64, 4.0), 5.0);
+        assert_eq!(hypot(3.0_f32, 4.0), 5.0);
+        assert_eq!(<f64 as Float>::from_f64(0.5), 0.5);
+        assert_eq!(<f32 as Float>::epsilon(), f32::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_one() {
+        assert_eq!(<i32 as Zero>::zero(), 0);
+        assert_eq!(<usize as One>::one(), 1);
+        assert_eq!(<f64 as Zero>::zero(), 0.0);
+        assert_eq!(<f32 as One>::one(), 1.0);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::vector::Vector;
+
+/// 台形則により等間隔の標本値を数値積分する関数
+///
+/// `∫ y dx ≈ dx (y_0 / 2 + y_1 + ... + y_(n-2) + y_(n-1) / 2)` を計算する。
+/// 標本が2点未満の場合は0を返す。
+///
+/// # 引数
+///
+/// * `y` - 等間隔の標本値
+/// * `dx` - 標本の間隔
+///
+/// # 戻り値
+///
+/// 積分値
+pub fn trapezoid(y: &Vector<f64>, dx: f64) -> f64 {
+    let n: usize = y.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let mut sum: f64 = 0.5 * (y[0] + y[n - 1]);
+    for i in 1..(n - 1) {
+        sum += y[i];
+    }
+    sum * dx
+}
+
+/// 台形則により等間隔の標本値を累積積分する関数
+///
+/// `I_0 = 0`、`I_i = I_(i-1) + dx (y_(i-1) + y_i) / 2` とし、各標本までの積分値を返す。
+/// 最後の要素は `trapezoid` の値と一致する。
+///
+/// # 引数
+///
+/// * `y` - 等間隔の標本値
+/// * `dx` - 標本の間隔
+///
+/// # 戻り値
+///
+/// 入力と同じ長さの累積積分値
+pub fn cumulative_trapezoid(y: &Vector<f64>, dx: f64) -> Vector<f64> {
+    let n: usize = y.len();
+    let mut result: Vector<f64> = Vector::new(n);
+    for i in 1..n {
+        result[i] = result[i - 1] + 0.5 * (y[i - 1] + y[i]) * dx;
+    }
+    result
+}
+
+/// 台形則により不等間隔の標本値を累積積分する関数
+///
+/// `I_0 = 0`、`I_i = I_(i-1) + (x_i - x_(i-1)) (y_(i-1) + y_i) / 2` とし、`∫ y dx` の各標本までの値を返す。
+/// `x` は単調でなくてもよく、変位増分に対する仕事 `∫ f dy` のような積分にも用いる。
+///
+/// # 引数
+///
+/// * `y` - 標本値
+/// * `x` - 各標本の座標
+///
+/// # 戻り値
+///
+/// 入力と同じ長さの累積積分値
+///
+/// # パニック
+///
+/// `y` と `x` のサイズが一致しない場合にパニックする
+pub fn cumulative_trapezoid_nonuniform(y: &Vector<f64>, x: &Vector<f64>) -> Vector<f64> {
+    assert_eq!(y.len(), x.len(), "標本値と座標のサイズが一致しません");
+    let n: usize = y.len();
+    let mut result: Vector<f64> = Vector::new(n);
+    for i in 1..n {
+        result[i] = result[i - 1] + 0.5 * (y[i - 1] + y[i]) * (x[i] - x[i - 1]);
+    }
+    result
+}
+
+/// Simpson則により等間隔の標本値を数値積分する関数
+///
+/// 標本数が奇数（区間数が偶数）の場合はSimpsonの1/3則
+/// `∫ y dx ≈ (dx / 3) (y_0 + 4 y_1 + 2 y_2 + ... + 4 y_(n-2) + y_(n-1))` を計算する。
+/// 標本数が偶数の場合は、最後の3区間にSimpsonの3/8則を用い、残りに1/3則を用いる。
+/// いずれも3次以下の多項式は厳密に積分される。
+/// 標本が2点の場合は台形則と一致し、2点未満の場合は0を返す。
+///
+/// # 引数
+///
+/// * `y` - 等間隔の標本値
+/// * `dx` - 標本の間隔
+///
+/// # 戻り値
+///
+/// 積分値
+pub fn simpson(y: &Vector<f64>, dx: f64) -> f64 {
+    let n: usize = y.len();
+    if n < 3 {
+        return trapezoid(y, dx);
+    }
+    // 1/3則を適用する標本数（奇数）
+    let m: usize = if n % 2 == 1 { n } else { n - 3 };
+
+    let mut sum: f64 = 0.0;
+    if m >= 3 {
+        sum += y[0] + y[m - 1];
+        for i in 1..(m - 1) {
+            sum += if i % 2 == 1 { 4.0 * y[i] } else { 2.0 * y[i] };
+        }
+        sum *= dx / 3.0;
+    }
+    if m < n {
+        // 最後の3区間に3/8則を適用する
+        let k: usize = n - 4;
+        sum += 3.0 * dx / 8.0 * (y[k] + 3.0 * y[k + 1] + 3.0 * y[k + 2] + y[k + 3]);
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 区間 `[0, 1]` を `n` 点で等分して `x²` を標本化する
+    fn squares(n: usize) -> (Vector<f64>, f64) {
+        let dx: f64 = 1.0 / (n - 1) as f64;
+        (Vector::from_fn(n, |i| (i as f64 * dx).powi(2)), dx)
+    }
+
+    #[test]
+    /// `x²` を `[0, 1]` で積分し、台形則の誤差が `dx² / 6` となることを確認するテスト
+    fn test_trapezoid_square() {
+        let (y, dx) = squares(101);
+        let expected: f64 = 1.0 / 3.0 + dx * dx / 6.0;
+        assert!((trapezoid(&y, dx) - expected).abs() < 1e-14);
+        assert_eq!(trapezoid(&Vector::from_vec(vec![1.0]), 0.1), 0.0);
+    }
+
+    #[test]
+    /// `x²` を `[0, 1]` で積分すると、標本数の偶奇によらずSimpson則で厳密な値となることを確認するテスト
+    fn test_simpson_square() {
+        for n in [3, 4, 5, 6, 11, 100, 101] {
+            let (y, dx) = squares(n);
+            assert!(
+                (simpson(&y, dx) - 1.0 / 3.0).abs() < 1e-14,
+                "n: {}, result: {}",
+                n,
+                simpson(&y, dx)
+            );
+        }
+        let (y, dx) = squares(2);
+        assert_eq!(simpson(&y, dx), trapezoid(&y, dx));
+    }
+
+    #[test]
+    /// 累積積分の最後の値が `trapezoid` と一致し、不等間隔でも `x²` の積分が台形則の値となることを確認するテスト
+    fn test_cumulative_trapezoid() {
+        let (y, dx) = squares(11);
+        let cumulative = cumulative_trapezoid(&y, dx);
+        assert_eq!(cumulative.len(), 11);
+        assert_eq!(cumulative[0], 0.0);
+        assert!((cumulative[10] - trapezoid(&y, dx)).abs() < 1e-15);
+
+        let x = Vector::from_vec(vec![0.0, 0.5, 0.75, 1.0]);
+        let y = Vector::from_fn(4, |i| x[i] * x[i]);
+        let cumulative = cumulative_trapezoid_nonuniform(&y, &x);
+        let expected: f64 =
+            0.5 * 0.25 * 0.5 + 0.25 * (0.25 + 0.5625) * 0.5 + 0.25 * (0.5625 + 1.0) * 0.5;
+        assert!((cumulative[3] - expected).abs() < 1e-15);
+        // 逆向きにたどると符号が反転する
+        let back = Vector::from_vec(vec![1.0, 0.0]);
+        assert_eq!(
+            cumulative_trapezoid_nonuniform(&Vector::from_vec(vec![2.0, 2.0]), &back)[1],
+            -2.0
+        );
+    }
+}
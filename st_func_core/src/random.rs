@@ -0,0 +1,96 @@
+/// 乱数生成器を表す構造体
+///
+/// 計算過程を把握できるように外部ライブラリを使わず、SplitMix64 法で64ビットの擬似乱数を生成する。
+/// 同じシードからは常に同じ乱数列が得られる。
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// 新しい乱数生成器を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `seed` - シード
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい乱数生成器
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// 64ビットの擬似乱数を生成する
+    ///
+    /// # 戻り値
+    ///
+    /// 一様に分布する64ビット整数
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z: u64 = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// 区間 `[0, 1)` の一様乱数を生成する
+    ///
+    /// # 戻り値
+    ///
+    /// 一様乱数
+    pub fn next_f64(&mut self) -> f64 {
+        // 上位53ビットを仮数部として用いる
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// 標準正規分布に従う乱数を生成する
+    ///
+    /// Box-Muller法 `z = √(-2 ln u1) · cos(2π u2)` による。
+    ///
+    /// # 戻り値
+    ///
+    /// 平均0、分散1の正規乱数
+    pub fn next_gaussian(&mut self) -> f64 {
+        let u1: f64 = 1.0 - self.next_f64(); // ln(0) を避けるため (0, 1] とする
+        let u2: f64 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let mut differs = false;
+        for _ in 0..10 {
+            let x = a.next_u64();
+            assert_eq!(x, b.next_u64());
+            differs |= x != c.next_u64();
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_rng_gaussian_moments() {
+        let mut rng = Rng::new(1);
+        let n: usize = 100_000;
+        let mut sum: f64 = 0.0;
+        let mut sum_sq: f64 = 0.0;
+        for _ in 0..n {
+            let u = rng.next_f64();
+            assert!((0.0..1.0).contains(&u));
+            let z = rng.next_gaussian();
+            sum += z;
+            sum_sq += z * z;
+        }
+        let mean: f64 = sum / n as f64;
+        let variance: f64 = sum_sq / n as f64 - mean * mean;
+        assert!(mean.abs() < 0.02, "mean: {}", mean);
+        assert!((variance - 1.0).abs() < 0.02, "variance: {}", variance);
+    }
+}
@@ -0,0 +1,621 @@
+use crate::dynamic::sdof::nigam_jennings;
+use crate::matrix::Matrix;
+use crate::numeric::cumulative_trapezoid;
+use crate::random::Rng;
+use crate::vector::Vector;
+use crate::window::tukey;
+use std::f64::consts::PI;
+
+/// 加速度記録から1次の最小二乗トレンドを除去する関数（基線補正）
+///
+/// 詳細は `baseline_correct_with_degree` を参照。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 基線補正後の加速度
+pub fn baseline_correct(y0_ddot: &Vector<f64>, delta_t: f64) -> Vector<f64> {
+    baseline_correct_with_degree(y0_ddot, delta_t, 1)
+}
+
+/// 加速度記録から最小二乗法で求めた多項式トレンドを除去する関数（基線補正）
+///
+/// 加速度に含まれる定数オフセットは速度に1次、変位に2次のドリフトを生じさせ、
+/// 1次のトレンドは速度に2次、変位に3次のドリフトを生じさせる。
+/// `degree` 次までのトレンドを除去することで、積分後の速度・変位のドリフトが抑えられる。
+/// ただし、記録の開始・終了時の速度や変位が0になることまでは保証しない。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `delta_t` - 時間刻み
+/// * `degree` - 除去する多項式の次数
+///
+/// # 戻り値
+///
+/// 基線補正後の加速度
+pub fn baseline_correct_with_degree(
+    y0_ddot: &Vector<f64>,
+    delta_t: f64,
+    degree: usize,
+) -> Vector<f64> {
+    let n: usize = y0_ddot.len();
+    if n == 0 {
+        return y0_ddot.clone();
+    }
+    let m: usize = degree.min(n - 1) + 1; // 係数の数
+
+    // 条件数を抑えるため、時刻を記録長で正規化して正規方程式を組み立てる
+    let duration: f64 = (n as f64 * delta_t).max(f64::MIN_POSITIVE);
+    let mut ata: Matrix<f64> = Matrix::new_square(m);
+    let mut aty: Vector<f64> = Vector::new(m);
+    for k in 0..n {
+        let tau: f64 = k as f64 * delta_t / duration;
+        let mut powers: Vector<f64> = Vector::new(m);
+        let mut p: f64 = 1.0;
+        for i in 0..m {
+            powers[i] = p;
+            p *= tau;
+        }
+        for i in 0..m {
+            for j in 0..m {
+                ata[(i, j)] += powers[i] * powers[j];
+            }
+            aty[i] += powers[i] * y0_ddot[k];
+        }
+    }
+    let coefficients: Vector<f64> = ata.solve(&aty);
+
+    let mut corrected: Vector<f64> = Vector::new(n);
+    for k in 0..n {
+        let tau: f64 = k as f64 * delta_t / duration;
+        let mut trend: f64 = 0.0;
+        let mut p: f64 = 1.0;
+        for i in 0..m {
+            trend += coefficients[i] * p;
+            p *= tau;
+        }
+        corrected[k] = y0_ddot[k] - trend;
+    }
+    corrected
+}
+
+/// 台形則により時刻歴を累積積分する関数
+///
+/// 加速度から速度、速度から変位を求める際に用いる。積分値は時刻0で0とする。
+/// 計算は [`cumulative_trapezoid`] による。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `delta_t` - 時間刻み
+///
+/// # 戻り値
+///
+/// 入力と同じ長さの累積積分値
+pub fn integrate(signal: &Vector<f64>, delta_t: f64) -> Vector<f64> {
+    cumulative_trapezoid(signal, delta_t)
+}
+
+/// 時刻歴を線形補間により新しい時間刻みで再サンプリングする関数
+///
+/// 新しい時刻 `0, dt_new, 2 dt_new, ...` のうち、元の記録長 `(n - 1) dt_old` 以内のものについて値を求める。
+/// 丸め誤差によって最後のサンプルが落ちないよう、記録長との比較には微小な許容値を設ける。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `dt_old` - 元の時間刻み
+/// * `dt_new` - 新しい時間刻み
+///
+/// # 戻り値
+///
+/// 再サンプリングした時刻歴
+///
+/// # パニック
+///
+/// 時間刻みが正でない場合にパニックする
+pub fn resample(signal: &Vector<f64>, dt_old: f64, dt_new: f64) -> Vector<f64> {
+    assert!(dt_old > 0.0 && dt_new > 0.0, "時間刻みが正ではありません");
+    let n: usize = signal.len();
+    if n < 2 {
+        return signal.clone();
+    }
+    let duration: f64 = (n - 1) as f64 * dt_old;
+    let m: usize = (duration / dt_new + 1e-9).floor() as usize + 1;
+
+    let mut result: Vector<f64> = Vector::new(m);
+    for j in 0..m {
+        let position: f64 = j as f64 * dt_new / dt_old;
+        let index: usize = (position.floor() as usize).min(n - 2);
+        let fraction: f64 = (position - index as f64).min(1.0);
+        result[j] = signal[index] + (signal[index + 1] - signal[index]) * fraction;
+    }
+    result
+}
+
+/// 最大加速度が目標値となるように加速度記録を倍率調整する関数
+///
+/// 記録全体に `target_pga / max|a|` を乗じる。最大加速度が0の記録はそのまま返す。
+///
+/// # 引数
+///
+/// * `y0_ddot` - 地動加速度
+/// * `target_pga` - 目標とする最大加速度
+///
+/// # 戻り値
+///
+/// 倍率調整した加速度
+pub fn scale_to_pga(y0_ddot: &Vector<f64>, target_pga: f64) -> Vector<f64> {
+    let pga: f64 = y0_ddot.norm_inf();
+    if pga == 0.0 {
+        return y0_ddot.clone();
+    }
+    y0_ddot.scale(target_pga / pga)
+}
+
+/// 最大加速度（PGA）を求める関数
+///
+/// # 引数
+///
+/// * `acc` - 地動加速度
+///
+/// # 戻り値
+///
+/// 加速度の絶対値の最大値
+pub fn pga(acc: &Vector<f64>) -> f64 {
+    acc.norm_inf()
+}
+
+/// 最大速度（PGV）を求める関数
+///
+/// 加速度を [`baseline_correct`] で基線補正した後、[`integrate`] により台形則で積分して速度を求め、
+/// その絶対値の最大値を返す。速度の初期値は0とする。
+///
+/// # 引数
+///
+/// * `acc` - 地動加速度
+/// * `dt` - 時間刻み
+///
+/// # 戻り値
+///
+/// 速度の絶対値の最大値
+pub fn pgv(acc: &Vector<f64>, dt: f64) -> f64 {
+    let corrected: Vector<f64> = baseline_correct(acc, dt);
+    integrate(&corrected, dt).norm_inf()
+}
+
+/// 最大変位（PGD）を求める関数
+///
+/// 加速度を [`baseline_correct`] で基線補正した後、[`integrate`] により台形則で2回積分して変位を求め、
+/// その絶対値の最大値を返す。速度・変位の初期値はいずれも0とする。
+/// 基線補正は加速度に対してのみ行うため、長周期のノイズを含む記録では変位にドリフトが残る場合がある。
+///
+/// # 引数
+///
+/// * `acc` - 地動加速度
+/// * `dt` - 時間刻み
+///
+/// # 戻り値
+///
+/// 変位の絶対値の最大値
+pub fn pgd(acc: &Vector<f64>, dt: f64) -> f64 {
+    let corrected: Vector<f64> = baseline_correct(acc, dt);
+    let velocity: Vector<f64> = integrate(&corrected, dt);
+    integrate(&velocity, dt).norm_inf()
+}
+
+/// 最大値が1となるように時刻歴を正規化する関数
+///
+/// 絶対値の最大値で除す。最大値が0の時刻歴はそのまま返す。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+///
+/// # 戻り値
+///
+/// 正規化した時刻歴
+pub fn normalize(signal: &Vector<f64>) -> Vector<f64> {
+    scale_to_pga(signal, 1.0)
+}
+
+/// 時刻歴の開始部と終了部に余弦テーパーを掛ける関数
+///
+/// 記録長の `taper_fraction` の区間で、開始部は0から1へ、終了部は1から0へ余弦で滑らかに変化させ、
+/// 中央部はそのまま残す。記録の打ち切りによるスペクトルの漏れを抑えるために用いる。
+/// 係数は [`tukey`] 窓（`alpha = 2 taper_fraction`）と同じであり、
+/// `taper_fraction = 0` では時刻歴を変更せず、`taper_fraction = 0.5` では全体にHann窓を掛けることになる。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `taper_fraction` - 片側のテーパー区間が記録長に占める割合
+///
+/// # 戻り値
+///
+/// テーパーを掛けた時刻歴
+///
+/// # パニック
+///
+/// `taper_fraction` が0以上0.5以下でない場合にパニックする
+pub fn apply_taper(signal: &Vector<f64>, taper_fraction: f64) -> Vector<f64> {
+    assert!(
+        (0.0..=0.5).contains(&taper_fraction),
+        "テーパーの割合が範囲外です: {}",
+        taper_fraction
+    );
+    signal.hadamard(&tukey(signal.len(), 2.0 * taper_fraction))
+}
+
+/// 金井・田治見スペクトルに従う模擬地震動を生成する関数
+///
+/// 平均0、分散 `1 / Δt` の正規白色雑音 `w` を地盤を表す1質点系（金井・田治見フィルタ）に入力し、
+/// その絶対加速度を定常な模擬地震動とする。出力の（両側）パワースペクトル密度は
+///
+/// `S(ω) = S0 · (ω_g⁴ + 4 h_g² ω_g² ω²) / ((ω_g² - ω²)² + 4 h_g² ω_g² ω²)`
+///
+/// となる。ここで `S0` は白色雑音の強さであり、雑音の分散を `1 / Δt` とすることで
+/// 時間刻みによらず `S0 = 1 / (2π)` となる。このとき定常状態の出力の分散は
+/// `π S0 ω_g (1 + 4 h_g²) / (2 h_g) = ω_g (1 + 4 h_g²) / (4 h_g)` である。
+/// 振幅は `scale_to_pga` などで別途調整する。フィルタの応答は `nigam_jennings` で計算する。
+///
+/// # 引数
+///
+/// * `n` - データ数
+/// * `delta_t` - 時間刻み
+/// * `omega_g` - 地盤の卓越円振動数（硬質地盤で 15 rad/s 程度、軟弱地盤ほど小さい）
+/// * `h_g` - 地盤の減衰定数（0.6 程度がよく用いられる）
+/// * `seed` - 乱数のシード
+///
+/// # 戻り値
+///
+/// 模擬地震動の加速度
+pub fn kanai_tajimi(n: usize, delta_t: f64, omega_g: f64, h_g: f64, seed: u64) -> Vector<f64> {
+    let mut rng = Rng::new(seed);
+    let intensity: f64 = 1.0 / delta_t.sqrt(); // 時間刻みによらず S0 = 1 / (2π) とする
+    let mut white_noise: Vector<f64> = Vector::new(n);
+    for i in 0..n {
+        white_noise[i] = intensity * rng.next_gaussian();
+    }
+    nigam_jennings(&white_noise, delta_t, omega_g, h_g).absolute_acceleration
+}
+
+/// Butterworthバンドパスフィルタを前後方向に適用する関数（ゼロ位相フィルタ）
+///
+/// 次数 `order` のButterworthハイパスフィルタ（遮断振動数 `f_low`）とローパスフィルタ
+/// （遮断振動数 `f_high`）を双一次変換（周波数プリワーピング付き）で離散化し、2次セクションの縦続接続として構成する。
+/// 信号を順方向に通した後に逆方向にも通すことで位相のずれを打ち消す。
+///
+/// 前後方向に適用するため振幅特性は元のフィルタの2乗となり、実質的な次数は `2 · order`、
+/// 遮断振動数での振幅比は `1/√2` ではなく `1/2`（-6 dB）となる。
+///
+/// # 引数
+///
+/// * `signal` - 時刻歴
+/// * `delta_t` - 時間刻み
+/// * `f_low` - 低域側の遮断振動数 [Hz]
+/// * `f_high` - 高域側の遮断振動数 [Hz]
+/// * `order` - フィルタの次数
+///
+/// # 戻り値
+///
+/// フィルタを適用した時刻歴
+///
+/// # パニック
+///
+/// `0 < f_low < f_high < 1 / (2 delta_t)` を満たさない場合、または次数が0の場合にパニックする
+pub fn butterworth_bandpass(
+    signal: &Vector<f64>,
+    delta_t: f64,
+    f_low: f64,
+    f_high: f64,
+    order: usize,
+) -> Vector<f64> {
+    let nyquist: f64 = 0.5 / delta_t;
+    assert!(
+        0.0 < f_low && f_low < f_high && f_high < nyquist,
+        "遮断振動数が不正です: {} Hz, {} Hz (ナイキスト振動数: {} Hz)",
+        f_low,
+        f_high,
+        nyquist
+    );
+    assert!(order > 0, "フィルタの次数は1以上とします");
+
+    let mut sections: Vec<Biquad> = butterworth_sections(f_low, delta_t, order, true);
+    sections.extend(butterworth_sections(f_high, delta_t, order, false));
+
+    let mut data: Vec<f64> = signal.into_iter().copied().collect();
+    for section in &sections {
+        section.apply(&mut data);
+    }
+    data.reverse();
+    for section in &sections {
+        section.apply(&mut data);
+    }
+    data.reverse();
+    Vector::from_vec(data)
+}
+
+/// 2次のIIRフィルタ（双2次フィルタ）を表す構造体
+///
+/// 伝達関数は `H(z) = (b0 + b1 z⁻¹ + b2 z⁻²) / (1 + a1 z⁻¹ + a2 z⁻²)` とする。
+struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+}
+
+impl Biquad {
+    /// 初期状態を0としてフィルタを適用する（直接形II転置）
+    fn apply(&self, data: &mut [f64]) {
+        let mut z1: f64 = 0.0;
+        let mut z2: f64 = 0.0;
+        for x in data.iter_mut() {
+            let input: f64 = *x;
+            let output: f64 = self.b0 * input + z1;
+            z1 = self.b1 * input - self.a1 * output + z2;
+            z2 = self.b2 * input - self.a2 * output;
+            *x = output;
+        }
+    }
+}
+
+/// Butterworthフィルタを2次セクションに分解して設計する
+///
+/// 第 `k` セクションの Q 値を `1 / (2 sin((2k + 1)π / (2N)))` とし、次数が奇数の場合は1次セクションを加える。
+fn butterworth_sections(cutoff: f64, delta_t: f64, order: usize, highpass: bool) -> Vec<Biquad> {
+    let w0: f64 = 2.0 * PI * cutoff * delta_t;
+    let (sin_w0, cos_w0) = (w0.sin(), w0.cos());
+    let mut sections: Vec<Biquad> = Vec::new();
+
+    for k in 0..order / 2 {
+        let q: f64 = 1.0 / (2.0 * ((2 * k + 1) as f64 * PI / (2 * order) as f64).sin());
+        let alpha: f64 = sin_w0 / (2.0 * q);
+        let a0: f64 = 1.0 + alpha;
+        let (b0, b1) = if highpass {
+            ((1.0 + cos_w0) / 2.0, -(1.0 + cos_w0))
+        } else {
+            ((1.0 - cos_w0) / 2.0, 1.0 - cos_w0)
+        };
+        sections.push(Biquad {
+            b0: b0 / a0,
+            b1: b1 / a0,
+            b2: b0 / a0,
+            a1: -2.0 * cos_w0 / a0,
+            a2: (1.0 - alpha) / a0,
+        });
+    }
+
+    if order % 2 == 1 {
+        let k: f64 = (w0 / 2.0).tan();
+        let (b0, b1) = if highpass {
+            (1.0 / (1.0 + k), -1.0 / (1.0 + k))
+        } else {
+            (k / (1.0 + k), k / (1.0 + k))
+        };
+        sections.push(Biquad {
+            b0,
+            b1,
+            b2: 0.0,
+            a1: (k - 1.0) / (k + 1.0),
+            a2: 0.0,
+        });
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::window::hann;
+    use std::f64::consts::PI;
+
+    #[test]
+    /// 線形のドリフトを含む記録を基線補正すると平均がほぼ0になることを確認するテスト
+    fn test_baseline_correct_linear_drift() {
+        let delta_t: f64 = 0.01;
+        let n: usize = 1000;
+        let mut y0_ddot: Vector<f64> = Vector::new(n);
+        let mut drift: Vector<f64> = Vector::new(n);
+        for i in 0..n {
+            let t: f64 = i as f64 * delta_t;
+            drift[i] = 0.3 + 0.2 * t;
+            y0_ddot[i] = (2.0 * PI * t).sin() + drift[i];
+        }
+        let corrected = baseline_correct(&y0_ddot, delta_t);
+        let mean: f64 = corrected.into_iter().sum::<f64>() / n as f64;
+        assert!(mean.abs() < 1e-10, "mean: {}", mean);
+    }
+
+    #[test]
+    /// 多項式トレンドのみの記録は次数を合わせれば完全に除去されることを確認するテスト
+    fn test_baseline_correct_with_degree() {
+        let delta_t: f64 = 0.02;
+        let mut y0_ddot: Vector<f64> = Vector::new(200);
+        for i in 0..y0_ddot.len() {
+            let t: f64 = i as f64 * delta_t;
+            y0_ddot[i] = 1.0 - 0.5 * t + 0.25 * t * t;
+        }
+        let corrected = baseline_correct_with_degree(&y0_ddot, delta_t, 2);
+        assert!(corrected.norm_inf() < 1e-10);
+    }
+
+    #[test]
+    /// 線形ランプの積分が解析解と一致することを確認するテスト
+    fn test_integrate_linear_ramp() {
+        let delta_t: f64 = 0.1;
+        let a: f64 = 2.0;
+        let b: f64 = 0.5;
+        let mut signal: Vector<f64> = Vector::new(50);
+        for i in 0..signal.len() {
+            signal[i] = a * i as f64 * delta_t + b;
+        }
+        let result = integrate(&signal, delta_t);
+        assert_eq!(result.len(), signal.len());
+        assert_eq!(result[0], 0.0);
+        for i in 0..result.len() {
+            let t: f64 = i as f64 * delta_t;
+            let expected: f64 = 0.5 * a * t * t + b * t;
+            assert!((result[i] - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    /// 線形ランプを再サンプリングすると補間値が元の直線上にあることを確認するテスト
+    fn test_resample_linear_ramp() {
+        let dt_old: f64 = 0.01;
+        let mut signal: Vector<f64> = Vector::new(101);
+        for i in 0..signal.len() {
+            signal[i] = 2.0 * i as f64 * dt_old;
+        }
+
+        // 間引き: 終点のサンプルを落とさない
+        let coarse = resample(&signal, dt_old, 0.02);
+        assert_eq!(coarse.len(), 51);
+        assert!((coarse[50] - 2.0).abs() < 1e-12);
+
+        // 細分化: 中間点は線形補間される
+        let fine = resample(&signal, dt_old, 0.005);
+        assert_eq!(fine.len(), 201);
+        for j in 0..fine.len() {
+            assert!((fine[j] - 2.0 * j as f64 * 0.005).abs() < 1e-12);
+        }
+
+        // 割り切れない時間刻み
+        let odd = resample(&signal, dt_old, 0.03);
+        assert_eq!(odd.len(), 34);
+        assert!((odd[33] - 2.0 * 0.99).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scale_to_pga() {
+        let y0_ddot = Vector::from_vec(vec![0.5, -2.0, 1.0]);
+        let scaled = scale_to_pga(&y0_ddot, 300.0);
+        assert!((scaled.norm_inf() - 300.0).abs() < 1e-12);
+        assert!((scaled[0] - 75.0).abs() < 1e-12);
+        assert!((scaled[1] + 300.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let signal = Vector::from_vec(vec![0.5, -2.0, 1.0]);
+        let normalized = normalize(&signal);
+        assert_eq!(normalized.norm_inf(), 1.0);
+        assert_eq!(normalized[0], 0.25);
+
+        // 最大値が0の場合は変更しない
+        let zero: Vector<f64> = Vector::new(3);
+        let normalized = normalize(&zero);
+        assert_eq!(normalized.len(), 3);
+        assert_eq!(normalized.norm_inf(), 0.0);
+    }
+
+    #[test]
+    fn test_kanai_tajimi_deterministic() {
+        let a = kanai_tajimi(500, 0.01, 15.6, 0.6, 7);
+        let b = kanai_tajimi(500, 0.01, 15.6, 0.6, 7);
+        let c = kanai_tajimi(500, 0.01, 15.6, 0.6, 8);
+        assert_eq!(a.len(), 500);
+        for i in 0..a.len() {
+            assert_eq!(a[i], b[i]);
+        }
+        assert!(!a.approx_eq(&c, 1e-6));
+        assert!(a.norm_inf() > 0.0);
+    }
+
+    #[test]
+    /// 出力の分散が時間刻みによらず理論値 `ω_g (1 + 4 h_g²) / (4 h_g)` に近いことを確認するテスト
+    fn test_kanai_tajimi_variance_independent_of_time_step() {
+        let omega_g: f64 = 15.6;
+        let h_g: f64 = 0.6;
+        let expected: f64 = omega_g * (1.0 + 4.0 * h_g * h_g) / (4.0 * h_g);
+        for delta_t in [0.01, 0.005] {
+            let duration: f64 = 400.0;
+            let n: usize = (duration / delta_t) as usize;
+            let acc = kanai_tajimi(n, delta_t, omega_g, h_g, 11);
+            // 初期の過渡応答を除いて分散を求める
+            let start: usize = (5.0 / delta_t) as usize;
+            let variance: f64 = acc.slice(start, n).variance();
+            assert!(
+                (variance / expected - 1.0).abs() < 0.1,
+                "delta_t: {}, variance: {}, expected: {}",
+                delta_t,
+                variance,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_butterworth_bandpass_removes_offset() {
+        let delta_t: f64 = 0.01;
+        let n: usize = 4000;
+        let signal: Vector<f64> =
+            Vector::from_fn(n, |i| 2.0 + (2.0 * PI * 2.0 * i as f64 * delta_t).sin());
+        let filtered = butterworth_bandpass(&signal, delta_t, 0.2, 10.0, 4);
+        assert_eq!(filtered.len(), n);
+
+        // 端部の過渡応答を除いた区間で直流成分が除去され、通過域の正弦波が保たれる
+        let middle: Vector<f64> = filtered.slice(1000, 3000);
+        let mean: f64 = middle.mean();
+        assert!(mean.abs() < 1e-2, "mean: {}", mean);
+        assert!(
+            (middle.norm_inf() - 1.0).abs() < 2e-2,
+            "peak: {}",
+            middle.norm_inf()
+        );
+    }
+
+    #[test]
+    fn test_butterworth_bandpass_odd_order() {
+        let delta_t: f64 = 0.01;
+        let signal: Vector<f64> = Vector::from_fn(3000, |_| 1.0);
+        let filtered = butterworth_bandpass(&signal, delta_t, 0.5, 20.0, 3);
+        assert!(filtered[1500].abs() < 1e-3, "value: {}", filtered[1500]);
+    }
+
+    #[test]
+    /// 余弦波の加速度 a = A cos(2πt/T) を2周期分与えると、
+    /// 速度 v = AT/(2π) sin(2πt/T)、変位 u = AT²/(4π²) (1 - cos(2πt/T)) となる
+    /// （離散化により残る僅かな平均値を基線補正で除去するため、許容誤差は1%とする）
+    fn test_peak_ground_motion() {
+        let amplitude: f64 = 3.0;
+        let period: f64 = 1.0;
+        let dt: f64 = 0.001;
+        let n: usize = (2.0 * period / dt) as usize;
+        let acc: Vector<f64> =
+            Vector::from_fn(n, |i| amplitude * (2.0 * PI * i as f64 * dt / period).cos());
+
+        assert!((pga(&acc) - amplitude).abs() < 1e-12);
+        let expected_pgv: f64 = amplitude * period / (2.0 * PI);
+        assert!((pgv(&acc, dt) - expected_pgv).abs() < 1e-2 * expected_pgv);
+        let expected_pgd: f64 = 2.0 * amplitude * period.powi(2) / (4.0 * PI * PI);
+        assert!((pgd(&acc, dt) - expected_pgd).abs() < 1e-2 * expected_pgd);
+    }
+
+    #[test]
+    /// テーパーにより両端が0となり、中央部は変化しないことを確認するテスト
+    fn test_apply_taper() {
+        let signal: Vector<f64> = Vector::from_fn(101, |i| 2.0 + (i as f64 * 0.3).sin());
+        let tapered = apply_taper(&signal, 0.1);
+        assert_eq!(tapered.len(), 101);
+        assert!(tapered[0].abs() < 1e-15);
+        assert!(tapered[100].abs() < 1e-15);
+        for i in 10..=90 {
+            assert_eq!(tapered[i], signal[i], "i: {}", i);
+        }
+        assert!(tapered[5].abs() < signal[5].abs());
+
+        // 割合が0の場合は変化せず、0.5の場合はHann窓と一致する
+        assert!(apply_taper(&signal, 0.0).approx_eq(&signal, 0.0));
+        let hann_tapered = signal.hadamard(&hann(101));
+        assert!(apply_taper(&signal, 0.5).approx_eq(&hann_tapered, 1e-12));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// 共役勾配法で連立一次方程式 `A x = b` を解く関数
+///
+/// 係数行列は対称正定値とする。初期値を `x = 0` とし、残差 `r = b - A x` のノルムが許容値を下回るまで
+///
+/// * `α = (rᵀ r) / (pᵀ A p)`、`x ← x + α p`、`r ← r - α A p`
+/// * `β = (r_newᵀ r_new) / (rᵀ r)`、`p ← r_new + β p`
+///
+/// を繰り返す。反復回数が上限に達した場合はその時点の近似解を返す。
+///
+/// # 引数
+///
+/// * `a` - 係数行列（対称正定値）
+/// * `b` - 右辺ベクトル
+/// * `tol` - 残差のノルムの許容値
+/// * `max_iter` - 最大反復回数
+///
+/// # 戻り値
+///
+/// 解ベクトル
+///
+/// # パニック
+///
+/// 行列が正方でない場合、または右辺ベクトルのサイズが一致しない場合にパニックする
+pub fn conjugate_gradient(
+    a: &Matrix<f64>,
+    b: &Vector<f64>,
+    tol: f64,
+    max_iter: usize,
+) -> Vector<f64> {
+    assert!(a.rows() == a.cols(), "行列が正方ではありません");
+    assert_eq!(b.len(), a.rows(), "右辺ベクトルのサイズが一致しません");
+    let mut x: Vector<f64> = Vector::new(b.len());
+    let mut r: Vector<f64> = b.clone();
+    let mut p: Vector<f64> = r.clone();
+    let mut rr: f64 = r.dot(&r);
+
+    for _ in 0..max_iter {
+        if rr.sqrt() < tol {
+            break;
+        }
+        let ap: Vector<f64> = a * &p;
+        let alpha: f64 = rr / p.dot(&ap);
+        x += p.scale(alpha);
+        r -= ap.scale(alpha);
+        let rr_new: f64 = r.dot(&r);
+        p = r.clone() + p.scale(rr_new / rr);
+        rr = rr_new;
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conjugate_gradient() {
+        let a = Matrix::from_vec(vec![
+            vec![4.0, 1.0, 0.0, 0.5],
+            vec![1.0, 3.0, -1.0, 0.0],
+            vec![0.0, -1.0, 5.0, 1.0],
+            vec![0.5, 0.0, 1.0, 2.0],
+        ]);
+        let b = Vector::from_vec(vec![1.0, -2.0, 3.0, 0.5]);
+        let x = conjugate_gradient(&a, &b, 1e-12, 100);
+        assert!(x.approx_eq(&a.solve(&b), 1e-10));
+        let residual = b - a * x;
+        assert!(residual.norm() < 1e-12);
+    }
+}
@@ -0,0 +1,193 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// 疎行列を表す構造体（CSR形式）
+///
+/// 0でない成分のみを行ごとに保持する。第 `i` 行の成分は `values[row_ptr[i]..row_ptr[i + 1]]` に、
+/// その列番号は `col_indices` の同じ範囲に列番号の昇順で格納する。
+///
+/// 構築はCOO形式（行番号、列番号、値の組の並び）から行い、行列ベクトル積はCSR形式で計算する。
+pub struct SparseMatrix {
+    rows: usize,
+    cols: usize,
+    row_ptr: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// COO形式の成分の並びから疎行列を作成する
+    ///
+    /// 同じ位置の成分が複数ある場合は和をとる（有限要素法の全体剛性行列の組み立てと同様）。
+    ///
+    /// # 引数
+    ///
+    /// * `rows` - 行数
+    /// * `cols` - 列数
+    /// * `triplets` - 行番号、列番号、値の組の並び
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい疎行列
+    ///
+    /// # パニック
+    ///
+    /// 成分の位置が行列の外にある場合にパニックする
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, f64)]) -> Self {
+        let mut sorted: Vec<(usize, usize, f64)> = triplets.to_vec();
+        for &(i, j, _) in &sorted {
+            assert!(
+                i < rows && j < cols,
+                "成分の位置が行列の外にあります: ({}, {})",
+                i,
+                j
+            );
+        }
+        sorted.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut row_ptr: Vec<usize> = vec![0; rows + 1];
+        let mut col_indices: Vec<usize> = Vec::with_capacity(sorted.len());
+        let mut values: Vec<f64> = Vec::with_capacity(sorted.len());
+        let mut last: Option<(usize, usize)> = None;
+        for (i, j, value) in sorted {
+            if last == Some((i, j)) {
+                *values.last_mut().unwrap() += value;
+                continue;
+            }
+            col_indices.push(j);
+            values.push(value);
+            row_ptr[i + 1] += 1;
+            last = Some((i, j));
+        }
+        for i in 0..rows {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+
+        SparseMatrix {
+            rows,
+            cols,
+            row_ptr,
+            col_indices,
+            values,
+        }
+    }
+
+    /// 密な行列から0でない成分を取り出して疎行列を作成する
+    ///
+    /// # 引数
+    ///
+    /// * `matrix` - 密な行列
+    ///
+    /// # 戻り値
+    ///
+    /// 新しい疎行列
+    pub fn from_dense(matrix: &Matrix<f64>) -> Self {
+        let mut triplets: Vec<(usize, usize, f64)> = Vec::new();
+        for i in 0..matrix.rows() {
+            for j in 0..matrix.cols() {
+                if matrix[(i, j)] != 0.0 {
+                    triplets.push((i, j, matrix[(i, j)]));
+                }
+            }
+        }
+        SparseMatrix::from_triplets(matrix.rows(), matrix.cols(), &triplets)
+    }
+
+    /// 密な行列に変換する
+    ///
+    /// # 戻り値
+    ///
+    /// 密な行列
+    pub fn to_dense(&self) -> Matrix<f64> {
+        let mut matrix: Matrix<f64> = Matrix::new(self.rows, self.cols);
+        for i in 0..self.rows {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                matrix[(i, self.col_indices[k])] = self.values[k];
+            }
+        }
+        matrix
+    }
+
+    /// 行数と列数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 行数と列数のタプル
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 保持している成分の数を返す
+    ///
+    /// # 戻り値
+    ///
+    /// 0でない成分の数
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 行列とベクトルの積を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `v` - 掛けるベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 積 `A v`
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが列数と一致しない場合にパニックする
+    pub fn matvec(&self, v: &Vector<f64>) -> Vector<f64> {
+        assert_eq!(v.len(), self.cols, "ベクトルのサイズが列数と一致しません");
+        let mut result: Vector<f64> = Vector::new(self.rows);
+        for i in 0..self.rows {
+            let mut sum: f64 = 0.0;
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] {
+                sum += self.values[k] * v[self.col_indices[k]];
+            }
+            result[i] = sum;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_matvec_matches_dense() {
+        let dense = Matrix::from_vec(vec![
+            vec![4.0, 0.0, 0.0, -1.0],
+            vec![0.0, 3.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![-1.0, 2.5, 0.0, 5.0],
+        ]);
+        let sparse = SparseMatrix::from_dense(&dense);
+        assert_eq!(sparse.shape(), (4, 4));
+        assert_eq!(sparse.nnz(), 6);
+        assert!(sparse.to_dense().approx_eq(&dense, 0.0));
+
+        let v = Vector::from_vec(vec![1.0, -2.0, 3.0, 0.5]);
+        let expected = dense.clone() * v.clone();
+        assert!(sparse.matvec(&v).approx_eq(&expected, 1e-15));
+    }
+
+    #[test]
+    fn test_sparse_from_triplets_sums_duplicates() {
+        let sparse = SparseMatrix::from_triplets(
+            2,
+            3,
+            &[(1, 2, 1.0), (0, 0, 2.0), (1, 2, 0.5), (0, 1, -1.0)],
+        );
+        assert_eq!(sparse.nnz(), 3);
+        let dense = sparse.to_dense();
+        assert_eq!(dense[(1, 2)], 1.5);
+        assert_eq!(dense[(0, 0)], 2.0);
+        assert_eq!(dense[(0, 1)], -1.0);
+        let result = sparse.matvec(&Vector::from_vec(vec![1.0, 1.0, 2.0]));
+        assert!(result.approx_eq(&Vector::from_vec(vec![1.0, 3.0]), 0.0));
+    }
+}
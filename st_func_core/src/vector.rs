@@ -1,48 +1,639 @@
-use std::ops::{Add, Index, IndexMut, Sub};
+use crate::complex::Complex;
+use crate::error::DimensionError;
+use crate::matrix::Matrix;
+use crate::num::Zero;
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
 
 /// ベクトルを表す構造体
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawVector<T>"))]
 pub struct Vector<T> {
     size: usize,
     data: Vec<T>,
 }
 
-impl<T: Default + Clone> Vector<T> {
+/// デシリアライズした検証前のベクトル
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawVector<T> {
+    size: usize,
+    data: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<RawVector<T>> for Vector<T> {
+    type Error = String;
+
+    /// サイズとデータの長さが一致することを確認してベクトルを作成する
+    fn try_from(raw: RawVector<T>) -> Result<Self, Self::Error> {
+        if raw.data.len() != raw.size {
+            return Err(format!(
+                "ベクトルのサイズとデータの長さが一致しません: {} と {}",
+                raw.size,
+                raw.data.len()
+            ));
+        }
+        Ok(Vector {
+            size: raw.size,
+            data: raw.data,
+        })
+    }
+}
+
+impl<T: Zero + Clone> Vector<T> {
     /// 新しいベクトルを作成する
     ///
-    /// # 引数
+    /// # 引数
+    ///
+    /// * `size` - ベクトルのサイズ
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル
+    pub fn new(size: usize) -> Self {
+        let data = vec![T::zero(); size];
+        Vector { size, data }
+    }
+
+    /// ベクタからベクトルを作成する
+    ///
+    /// # 引数
+    ///
+    /// * `data` - ベクトルのデータ
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル
+    pub fn from_vec(data: Vec<T>) -> Self {
+        let size = data.len();
+        Vector { size, data }
+    }
+
+    /// 関数から各要素を生成してベクトルを作成する
+    ///
+    /// # 引数
+    ///
+    /// * `size` - ベクトルのサイズ
+    /// * `f` - インデックスを受け取り要素を返す関数
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル
+    pub fn from_fn<F: FnMut(usize) -> T>(size: usize, f: F) -> Self {
+        let data = (0..size).map(f).collect();
+        Vector { size, data }
+    }
+
+    /// ベクトルの要素を行優先で並べ替えて行列を作成する
+    ///
+    /// 第 `k` 要素は行列の `(k / cols, k % cols)` 成分となる。`Matrix::flatten` の逆の操作である。
+    ///
+    /// # 引数
+    ///
+    /// * `rows` - 行数
+    /// * `cols` - 列数
+    ///
+    /// # 戻り値
+    ///
+    /// `rows×cols` の行列
+    ///
+    /// # パニック
+    ///
+    /// `rows * cols` がベクトルのサイズと一致しない場合にパニックする
+    pub fn reshape(&self, rows: usize, cols: usize) -> Matrix<T> {
+        assert_eq!(
+            rows * cols,
+            self.size,
+            "要素数が一致しません: {}×{} と {}",
+            rows,
+            cols,
+            self.size
+        );
+        Matrix::from_fn(rows, cols, |i, j| self.data[i * cols + j].clone())
+    }
+}
+
+impl<T> Vector<T> {
+    /// ベクトルのサイズを返す
+    ///
+    /// # 戻り値
+    ///
+    /// ベクトルのサイズ
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// ベクトルが空かどうかを返す
+    ///
+    /// # 戻り値
+    ///
+    /// 要素を持たない場合は `true`
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+impl<T: Clone> Vector<T> {
+    /// 部分ベクトルを取り出す
+    ///
+    /// # 引数
+    ///
+    /// * `start` - 開始位置（含む）
+    /// * `end` - 終了位置（含まない）
+    ///
+    /// # 戻り値
+    ///
+    /// 要素 `start..end` からなるベクトル
+    ///
+    /// # パニック
+    ///
+    /// 範囲がベクトルの外にある場合、または開始が終了より大きい場合にパニックする
+    pub fn slice(&self, start: usize, end: usize) -> Vector<T> {
+        assert!(
+            start <= end && end <= self.size,
+            "範囲が不正です: {}..{} (サイズ: {})",
+            start,
+            end,
+            self.size
+        );
+        Vector {
+            size: end - start,
+            data: self.data[start..end].to_vec(),
+        }
+    }
+
+    /// 2つのベクトルを連結する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 後ろに連結するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// `self` の要素に続けて `other` の要素を並べたベクトル
+    pub fn concat(&self, other: &Vector<T>) -> Vector<T> {
+        let mut data: Vec<T> = self.data.clone();
+        data.extend_from_slice(&other.data);
+        Vector {
+            size: self.size + other.size,
+            data,
+        }
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Zero + Copy,
+{
+    /// 2つのベクトルの内積を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 内積をとるベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 内積
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが一致しない場合にパニックする
+    pub fn dot(&self, other: &Vector<T>) -> T {
+        assert!(self.size == other.size);
+        let mut result = T::zero();
+        for i in 0..self.size {
+            result = result + (self[i] * other[i]);
+        }
+        result
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Zero + Copy,
+{
+    /// ベクトルをスカラー倍する
+    ///
+    /// # 引数
+    ///
+    /// * `factor` - 乗じるスカラー
+    ///
+    /// # 戻り値
+    ///
+    /// スカラー倍されたベクトル
+    pub fn scale(&self, factor: T) -> Vector<T> {
+        let mut result = Vector::new(self.size);
+        for i in 0..self.size {
+            result[i] = self[i] * factor;
+        }
+        result
+    }
+
+    /// 2つのベクトルの外積（直積）を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 右側のベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `(i, j)` 要素が `self[i] * other[j]` である `m×n` の行列
+    pub fn outer(&self, other: &Vector<T>) -> Matrix<T> {
+        Matrix::from_fn(self.size, other.size, |i, j| self[i] * other[j])
+    }
+
+    /// 2つのベクトルの要素ごとの積（アダマール積）を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 掛け合わせるベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 要素ごとの積のベクトル
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが一致しない場合にパニックする
+    pub fn hadamard(&self, other: &Vector<T>) -> Vector<T> {
+        assert!(self.size == other.size);
+        let mut result = Vector::new(self.size);
+        for i in 0..self.size {
+            result[i] = self[i] * other[i];
+        }
+        result
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Add<Output = T> + Sub<Output = T> + Zero + Copy,
+{
+    /// 2つのベクトルを加算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 加算するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 加算結果のベクトル。サイズが一致しない場合は `DimensionError`
+    pub fn try_add(&self, other: &Vector<T>) -> Result<Vector<T>, DimensionError> {
+        self.check_size(other)?;
+        let mut result = Vector::new(self.size);
+        for i in 0..self.size {
+            result[i] = self[i] + other[i];
+        }
+        Ok(result)
+    }
+
+    /// 2つのベクトルを減算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 減算するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 減算結果のベクトル。サイズが一致しない場合は `DimensionError`
+    pub fn try_sub(&self, other: &Vector<T>) -> Result<Vector<T>, DimensionError> {
+        self.check_size(other)?;
+        let mut result = Vector::new(self.size);
+        for i in 0..self.size {
+            result[i] = self[i] - other[i];
+        }
+        Ok(result)
+    }
+
+    /// 2つのベクトルのサイズが一致するか確認する
+    fn check_size(&self, other: &Vector<T>) -> Result<(), DimensionError> {
+        if self.size != other.size {
+            return Err(DimensionError::VectorSizeMismatch {
+                left: self.size,
+                right: other.size,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Vector<f64> {
+    /// 始点と終点を含む等間隔のベクトルを作成する
+    ///
+    /// # 引数
+    ///
+    /// * `start` - 始点
+    /// * `end` - 終点
+    /// * `n` - 要素数
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル。`n` が1の場合は `start` のみを要素にもつ
+    pub fn linspace(start: f64, end: f64, n: usize) -> Self {
+        let mut v = Vector::new(n);
+        if n == 1 {
+            v[0] = start;
+        } else if n > 1 {
+            let step = (end - start) / (n - 1) as f64;
+            for i in 0..n {
+                v[i] = start + i as f64 * step;
+            }
+            // 丸め誤差によらず終点を含める
+            v[n - 1] = end;
+        }
+        v
+    }
+
+    /// 始点から刻み幅ごとに、終点を含まない範囲で値を並べたベクトルを作成する
+    ///
+    /// # 引数
+    ///
+    /// * `start` - 始点
+    /// * `stop` - 終点（含まない）
+    /// * `step` - 刻み幅
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル
+    ///
+    /// # パニック
+    ///
+    /// 刻み幅が0の場合にパニックする
+    pub fn arange(start: f64, stop: f64, step: f64) -> Self {
+        assert!(step != 0.0, "刻み幅が0です");
+        let n = ((stop - start) / step).ceil().max(0.0) as usize;
+        let mut v = Vector::new(n);
+        for i in 0..n {
+            v[i] = start + i as f64 * step;
+        }
+        v
+    }
+
+    /// ベクトルのユークリッドノルム（L2ノルム）を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 各要素の二乗和の平方根
+    pub fn norm(&self) -> f64 {
+        let mut sum = 0.0;
+        for i in 0..self.size {
+            sum += self[i] * self[i];
+        }
+        sum.sqrt()
+    }
+
+    /// ベクトルのL1ノルムを計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 各要素の絶対値の和
+    pub fn norm_l1(&self) -> f64 {
+        let mut sum = 0.0;
+        for i in 0..self.size {
+            sum += self[i].abs();
+        }
+        sum
+    }
+
+    /// ベクトルの最大値ノルム（L∞ノルム）を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 各要素の絶対値の最大値。空のベクトルの場合は0
+    pub fn norm_inf(&self) -> f64 {
+        let mut max = 0.0;
+        for i in 0..self.size {
+            if self[i].abs() > max {
+                max = self[i].abs();
+            }
+        }
+        max
+    }
+
+    /// 2つのベクトルが許容値の範囲で等しいかどうかを判定する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 比較するベクトル
+    /// * `tol` - 要素ごとの差の絶対値の許容値
+    ///
+    /// # 戻り値
+    ///
+    /// サイズが一致し、すべての要素の差が許容値以下の場合は `true`
+    pub fn approx_eq(&self, other: &Vector<f64>, tol: f64) -> bool {
+        if self.size != other.size {
+            return false;
+        }
+        for i in 0..self.size {
+            if (self[i] - other[i]).abs() > tol {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// 3次元ベクトルの外積を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 右側のベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// 外積 `self × other`
+    ///
+    /// # パニック
+    ///
+    /// いずれかのベクトルのサイズが3でない場合にパニックする
+    pub fn cross(&self, other: &Vector<f64>) -> Vector<f64> {
+        assert!(
+            self.size == 3 && other.size == 3,
+            "外積は3次元ベクトルに対してのみ定義されます: {} と {}",
+            self.size,
+            other.size
+        );
+        Vector::from_vec(vec![
+            self[1] * other[2] - self[2] * other[1],
+            self[2] * other[0] - self[0] * other[2],
+            self[0] * other[1] - self[1] * other[0],
+        ])
+    }
+
+    /// 2つのベクトルのなす角を計算する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 比較するベクトル
+    ///
+    /// # 戻り値
+    ///
+    /// なす角（ラジアン、0 から π）
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが一致しない場合にパニックする
+    pub fn angle_between(&self, other: &Vector<f64>) -> f64 {
+        let cos: f64 = self.dot(other) / (self.norm() * other.norm());
+        // 丸め誤差で定義域を外れないようにする
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    /// 累積和を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `i` 要素が先頭から第 `i` 要素までの和であるベクトル
+    pub fn cumsum(&self) -> Vector<f64> {
+        let mut result: Vector<f64> = Vector::new(self.size);
+        let mut sum: f64 = 0.0;
+        for i in 0..self.size {
+            sum += self[i];
+            result[i] = sum;
+        }
+        result
+    }
+
+    /// 隣り合う要素の差分を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 第 `i` 要素が `self[i + 1] - self[i]` であるベクトル。
+    /// サイズは元のベクトルより1小さい（空のベクトルの場合は空）
+    pub fn diff(&self) -> Vector<f64> {
+        let mut result: Vector<f64> = Vector::new(self.size.saturating_sub(1));
+        for i in 0..result.len() {
+            result[i] = self[i + 1] - self[i];
+        }
+        result
+    }
+
+    /// 要素の和を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 要素の和。空のベクトルの場合は0
+    pub fn sum(&self) -> f64 {
+        let mut sum: f64 = 0.0;
+        for i in 0..self.size {
+            sum += self[i];
+        }
+        sum
+    }
+
+    /// 要素の平均を計算する
+    ///
+    /// # 戻り値
+    ///
+    /// 要素の平均。空のベクトルの場合は NaN
+    pub fn mean(&self) -> f64 {
+        self.sum() / self.size as f64
+    }
+
+    /// 要素の不偏分散を計算する
+    ///
+    /// 偏差の二乗和を `n - 1` で除す。
+    ///
+    /// # 戻り値
     ///
-    /// * `size` - ベクトルのサイズ
+    /// 不偏分散。要素数が2未満の場合は NaN
+    pub fn variance(&self) -> f64 {
+        if self.size < 2 {
+            return f64::NAN;
+        }
+        let mean: f64 = self.mean();
+        let mut sum: f64 = 0.0;
+        for i in 0..self.size {
+            sum += (self[i] - mean) * (self[i] - mean);
+        }
+        sum / (self.size - 1) as f64
+    }
+
+    /// 要素の標準偏差を計算する
     ///
     /// # 戻り値
     ///
-    /// 新しいベクトル
-    pub fn new(size: usize) -> Self {
-        let data = vec![T::default(); size];
-        Vector { size, data }
+    /// 不偏分散の平方根。要素数が2未満の場合は NaN
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
     }
 
-    /// ベクタからベクトルを作成する
+    /// 最大の要素を返す
     ///
-    /// # 引数
+    /// # 戻り値
     ///
-    /// * `data` - ベクトルのデータ
+    /// 最大の要素
+    ///
+    /// # パニック
+    ///
+    /// ベクトルが空の場合にパニックする
+    pub fn max(&self) -> f64 {
+        self[self.argmax()]
+    }
+
+    /// 最小の要素を返す
     ///
     /// # 戻り値
     ///
-    /// 新しいベクトル
-    pub fn from_vec(data: Vec<T>) -> Self {
-        let size = data.len();
-        Vector { size, data }
+    /// 最小の要素
+    ///
+    /// # パニック
+    ///
+    /// ベクトルが空の場合にパニックする
+    pub fn min(&self) -> f64 {
+        self[self.argmin()]
     }
 
-    /// ベクトルのサイズを返す
+    /// 最大の要素のインデックスを返す
     ///
     /// # 戻り値
     ///
-    /// ベクトルのサイズ
-    pub fn len(&self) -> usize {
-        self.size
+    /// 最大の要素のインデックス。最大の要素が複数ある場合は最初のもの
+    ///
+    /// # パニック
+    ///
+    /// ベクトルが空の場合にパニックする
+    pub fn argmax(&self) -> usize {
+        assert!(self.size > 0, "ベクトルが空です");
+        let mut index: usize = 0;
+        for i in 1..self.size {
+            if self[i] > self[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    /// 最小の要素のインデックスを返す
+    ///
+    /// # 戻り値
+    ///
+    /// 最小の要素のインデックス。最小の要素が複数ある場合は最初のもの
+    ///
+    /// # パニック
+    ///
+    /// ベクトルが空の場合にパニックする
+    pub fn argmin(&self) -> usize {
+        assert!(self.size > 0, "ベクトルが空です");
+        let mut index: usize = 0;
+        for i in 1..self.size {
+            if self[i] < self[index] {
+                index = i;
+            }
+        }
+        index
+    }
+}
+
+impl Vector<Complex<f64>> {
+    /// 各要素を共役複素数としたベクトルを作成する
+    ///
+    /// # 戻り値
+    ///
+    /// 共役複素数を要素とするベクトル
+    pub fn conjugate(&self) -> Vector<Complex<f64>> {
+        Vector::from_fn(self.size, |i| self.data[i].conj())
     }
 }
 
@@ -80,7 +671,7 @@ impl<T> IndexMut<usize> for Vector<T> {
 
 impl<T> Add for Vector<T>
 where
-    T: Add<Output = T> + Default + Copy,
+    T: Add<Output = T> + Zero + Copy,
 {
     type Output = Vector<T>;
 
@@ -109,7 +700,7 @@ where
 
 impl<T> Sub for Vector<T>
 where
-    T: Sub<Output = T> + Default + Copy,
+    T: Sub<Output = T> + Zero + Copy,
 {
     type Output = Vector<T>;
 
@@ -136,6 +727,148 @@ where
     }
 }
 
+impl<T> AddAssign for Vector<T>
+where
+    T: Add<Output = T> + Copy,
+{
+    /// ベクトルを加算して自身を更新する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 加算するベクトル
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが一致しない場合にパニックする
+    fn add_assign(&mut self, other: Vector<T>) {
+        assert!(self.size == other.size);
+        for i in 0..self.size {
+            self[i] = self[i] + other[i];
+        }
+    }
+}
+
+impl<T> SubAssign for Vector<T>
+where
+    T: Sub<Output = T> + Copy,
+{
+    /// ベクトルを減算して自身を更新する
+    ///
+    /// # 引数
+    ///
+    /// * `other` - 減算するベクトル
+    ///
+    /// # パニック
+    ///
+    /// ベクトルのサイズが一致しない場合にパニックする
+    fn sub_assign(&mut self, other: Vector<T>) {
+        assert!(self.size == other.size);
+        for i in 0..self.size {
+            self[i] = self[i] - other[i];
+        }
+    }
+}
+
+impl<T> Mul<T> for Vector<T>
+where
+    T: Mul<Output = T> + Zero + Copy,
+{
+    type Output = Vector<T>;
+
+    /// ベクトルをスカラー倍する
+    ///
+    /// # 引数
+    ///
+    /// * `factor` - 乗じるスカラー
+    ///
+    /// # 戻り値
+    ///
+    /// スカラー倍されたベクトル
+    fn mul(self, factor: T) -> Vector<T> {
+        self.scale(factor)
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// ベクトルを要素のイテレータに変換する
+    ///
+    /// # 戻り値
+    ///
+    /// 要素を所有権ごと返すイテレータ
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    /// ベクトルの要素への参照のイテレータを返す
+    ///
+    /// # 戻り値
+    ///
+    /// 要素への参照を返すイテレータ
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T> {
+    /// イテレータからベクトルを作成する
+    ///
+    /// # 引数
+    ///
+    /// * `iter` - 要素のイテレータ
+    ///
+    /// # 戻り値
+    ///
+    /// 新しいベクトル
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let data: Vec<T> = iter.into_iter().collect();
+        let size = data.len();
+        Vector { size, data }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector<T> {
+    /// ベクトルを整形して出力する
+    ///
+    /// 書式指定の精度（例: `{:.3}`）が各要素に適用される。
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, x) in self.data.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match f.precision() {
+                Some(p) => write!(f, "{:.*}", p, x)?,
+                None => write!(f, "{}", x)?,
+            }
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl From<ndarray::Array1<f64>> for Vector<f64> {
+    /// `ndarray` の1次元配列からベクトルへ変換する。
+    fn from(array: ndarray::Array1<f64>) -> Self {
+        Vector::from_vec(array.to_vec())
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl From<Vector<f64>> for ndarray::Array1<f64> {
+    /// ベクトルから `ndarray` の1次元配列へ変換する。
+    fn from(vector: Vector<f64>) -> Self {
+        ndarray::Array1::from_vec(vector.data)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +880,16 @@ mod tests {
         assert_eq!(v[2], 0.0);
     }
 
+    #[test]
+    fn test_vector_len() {
+        let v: Vector<f64> = Vector::new(5);
+        assert_eq!(v.len(), 5);
+        assert!(!v.is_empty());
+        let empty: Vector<f64> = Vector::new(0);
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+    }
+
     #[test]
     fn test_vector_addition() {
         let v1 = Vector::from_vec(vec![1.0, 2.0, 3.0]);
@@ -167,6 +910,162 @@ mod tests {
         assert_eq!(v3[2], 3.0);
     }
 
+    #[test]
+    fn test_vector_add_assign() {
+        let mut v = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let w = Vector::from_vec(vec![4.0, 5.0, 6.0]);
+        let ptr = v.data.as_ptr();
+        v += w;
+        assert_eq!(v.data.as_ptr(), ptr);
+        assert_eq!(v[0], 5.0);
+        assert_eq!(v[1], 7.0);
+        assert_eq!(v[2], 9.0);
+    }
+
+    #[test]
+    fn test_vector_sub_assign() {
+        let mut v = Vector::from_vec(vec![4.0, 5.0, 6.0]);
+        let w = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let ptr = v.data.as_ptr();
+        v -= w;
+        assert_eq!(v.data.as_ptr(), ptr);
+        assert_eq!(v[0], 3.0);
+        assert_eq!(v[1], 3.0);
+        assert_eq!(v[2], 3.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_add_assign_size_mismatch() {
+        let mut v = Vector::from_vec(vec![1.0, 2.0]);
+        v += Vector::from_vec(vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_vector_into_iter() {
+        let v = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let sum: f64 = (&v).into_iter().sum();
+        assert_eq!(sum, 6.0);
+        let doubled: Vec<f64> = v.into_iter().map(|x| x * 2.0).collect();
+        assert_eq!(doubled, vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_vector_from_iter() {
+        let v: Vector<f64> = (0..4).map(|i| i as f64 * 0.5).collect();
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[0], 0.0);
+        assert_eq!(v[1], 0.5);
+        assert_eq!(v[3], 1.5);
+    }
+
+    #[test]
+    fn test_vector_display() {
+        let v = Vector::from_vec(vec![1.0, -2.5, 3.0]);
+        assert_eq!(format!("{}", v), "[1, -2.5, 3]");
+        assert_eq!(format!("{:.1}", v), "[1.0, -2.5, 3.0]");
+    }
+
+    #[test]
+    fn test_vector_try_add_sub() {
+        let v1 = Vector::from_vec(vec![1.0, 2.0]);
+        let v2 = Vector::from_vec(vec![3.0, 5.0]);
+        let sum = v1.try_add(&v2).unwrap();
+        assert_eq!(sum[0], 4.0);
+        assert_eq!(sum[1], 7.0);
+        let diff = v2.try_sub(&v1).unwrap();
+        assert_eq!(diff[0], 2.0);
+        assert_eq!(diff[1], 3.0);
+    }
+
+    #[test]
+    fn test_vector_try_add_sub_mismatch() {
+        let v1 = Vector::from_vec(vec![1.0, 2.0]);
+        let v2 = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let expected = DimensionError::VectorSizeMismatch { left: 2, right: 3 };
+        assert_eq!(v1.try_add(&v2).unwrap_err(), expected);
+        assert_eq!(v1.try_sub(&v2).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_vector_dot() {
+        let v1 = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let v2 = Vector::from_vec(vec![4.0, 5.0, 6.0]);
+        assert_eq!(v1.dot(&v2), 32.0);
+    }
+
+    #[test]
+    fn test_vector_scalar_multiplication() {
+        let v1 = Vector::from_vec(vec![1.0, 2.0]);
+        let v2 = v1 * 3.0;
+        assert_eq!(v2.len(), 2);
+        assert_eq!(v2[0], 3.0);
+        assert_eq!(v2[1], 6.0);
+    }
+
+    #[test]
+    fn test_vector_scale() {
+        let v1 = Vector::from_vec(vec![1.0, -2.0, 4.0]);
+        let v2 = v1.scale(0.5);
+        assert_eq!(v2[0], 0.5);
+        assert_eq!(v2[1], -1.0);
+        assert_eq!(v2[2], 2.0);
+    }
+
+    #[test]
+    fn test_vector_linspace() {
+        let v = Vector::linspace(0.0, 1.0, 5);
+        let expected = [0.0, 0.25, 0.5, 0.75, 1.0];
+        assert_eq!(v.len(), 5);
+        for i in 0..5 {
+            assert_eq!(v[i], expected[i]);
+        }
+        assert_eq!(Vector::linspace(2.0, 3.0, 1)[0], 2.0);
+        assert!(Vector::linspace(0.0, 1.0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_vector_arange() {
+        let v = Vector::arange(0.0, 1.0, 0.25);
+        let expected = [0.0, 0.25, 0.5, 0.75];
+        assert_eq!(v.len(), 4);
+        for i in 0..4 {
+            assert_eq!(v[i], expected[i]);
+        }
+        let v = Vector::arange(1.0, 0.0, -0.5);
+        assert_eq!(v.len(), 2);
+        assert_eq!(v[1], 0.5);
+        assert!(Vector::arange(1.0, 0.0, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_vector_norm() {
+        let v = Vector::from_vec(vec![3.0, 4.0]);
+        assert_eq!(v.norm(), 5.0);
+    }
+
+    #[test]
+    fn test_vector_norm_l1() {
+        let v = Vector::from_vec(vec![3.0, -4.0, 1.0]);
+        assert_eq!(v.norm_l1(), 8.0);
+    }
+
+    #[test]
+    fn test_vector_norm_inf() {
+        let v = Vector::from_vec(vec![3.0, -4.0, 1.0]);
+        assert_eq!(v.norm_inf(), 4.0);
+    }
+
+    #[test]
+    fn test_vector_hadamard() {
+        let v1 = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let v2 = Vector::from_vec(vec![4.0, 5.0, 6.0]);
+        let v3 = v1.hadamard(&v2);
+        assert_eq!(v3[0], 4.0);
+        assert_eq!(v3[1], 10.0);
+        assert_eq!(v3[2], 18.0);
+    }
+
     #[test]
     fn test_vector_indexing() {
         let mut v: Vector<f64> = Vector::new(3);
@@ -177,4 +1076,187 @@ mod tests {
         assert_eq!(v[1], 2.0);
         assert_eq!(v[2], 3.0);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vector_serde_round_trip() {
+        let v = Vector::from_vec(vec![1.0, -2.5, 3.25]);
+        let json = serde_json::to_string(&v).unwrap();
+        let restored: Vector<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), v.len());
+        for i in 0..v.len() {
+            assert_eq!(restored[i], v[i]);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vector_serde_rejects_inconsistent_size() {
+        let result = serde_json::from_str::<Vector<f64>>(r#"{"size":3,"data":[1.0]}"#);
+        let message = result.unwrap_err().to_string();
+        assert!(
+            message.contains("ベクトルのサイズとデータの長さが一致しません"),
+            "{}",
+            message
+        );
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn test_vector_ndarray_round_trip() {
+        let v = Vector::from_vec(vec![1.0, -2.5, 3.0]);
+        let array: ndarray::Array1<f64> = v.clone().into();
+        assert_eq!(array, ndarray::arr1(&[1.0, -2.5, 3.0]));
+        let restored: Vector<f64> = array.into();
+        assert_eq!(restored.len(), 3);
+        for i in 0..3 {
+            assert_eq!(restored[i], v[i]);
+        }
+    }
+
+    #[test]
+    fn test_vector_from_fn() {
+        let v = Vector::from_fn(4, |i| (i * i) as f64);
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[0], 0.0);
+        assert_eq!(v[3], 9.0);
+    }
+
+    #[test]
+    fn test_vector_approx_eq() {
+        let a = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let b = Vector::from_vec(vec![1.0, 2.0 + 1e-10, 3.0]);
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-12));
+
+        // サイズが異なる場合は等しくない
+        let c = Vector::from_vec(vec![1.0, 2.0]);
+        assert!(!a.approx_eq(&c, 1e9));
+    }
+
+    #[test]
+    fn test_vector_cross() {
+        let x = Vector::from_vec(vec![1.0, 0.0, 0.0]);
+        let y = Vector::from_vec(vec![0.0, 1.0, 0.0]);
+        let z = Vector::from_vec(vec![0.0, 0.0, 1.0]);
+        assert!(x.cross(&y).approx_eq(&z, 1e-15));
+        assert!(y.cross(&x).approx_eq(&z.scale(-1.0), 1e-15));
+    }
+
+    #[test]
+    fn test_vector_angle_between() {
+        let x = Vector::from_vec(vec![1.0, 0.0, 0.0]);
+        let y = Vector::from_vec(vec![0.0, 2.0, 0.0]);
+        let d = Vector::from_vec(vec![1.0, 1.0, 0.0]);
+        assert!((x.angle_between(&y) - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_between(&d) - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert!(x.angle_between(&x).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_vector_cumsum() {
+        let v = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        assert!(
+            v.cumsum()
+                .approx_eq(&Vector::from_vec(vec![1.0, 3.0, 6.0]), 0.0)
+        );
+    }
+
+    #[test]
+    fn test_vector_diff() {
+        let v = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        let d = v.diff();
+        assert_eq!(d.len(), 2);
+        assert!(d.approx_eq(&Vector::from_vec(vec![1.0, 1.0]), 0.0));
+        assert!(Vector::<f64>::new(0).diff().is_empty());
+    }
+
+    #[test]
+    fn test_vector_statistics() {
+        let v = Vector::from_vec(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(v.sum(), 40.0);
+        assert_eq!(v.mean(), 5.0);
+        // 偏差の二乗和は32
+        assert!((v.variance() - 32.0 / 7.0).abs() < 1e-12);
+        assert!((v.std_dev() - (32.0_f64 / 7.0).sqrt()).abs() < 1e-12);
+        // 母分散では標準偏差は2
+        assert!(((v.variance() * 7.0 / 8.0).sqrt() - 2.0).abs() < 1e-12);
+
+        let single = Vector::from_vec(vec![1.0]);
+        assert!(single.variance().is_nan());
+        assert!(Vector::<f64>::new(0).mean().is_nan());
+    }
+
+    #[test]
+    fn test_vector_max_min() {
+        let v = Vector::from_vec(vec![0.5, -3.0, 7.5, 2.0, 7.5]);
+        assert_eq!(v.max(), 7.5);
+        assert_eq!(v.argmax(), 2);
+        assert_eq!(v.min(), -3.0);
+        assert_eq!(v.argmin(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "ベクトルが空です")]
+    fn test_vector_argmax_empty() {
+        Vector::<f64>::new(0).argmax();
+    }
+
+    #[test]
+    fn test_vector_slice() {
+        let v = Vector::from_vec(vec![1, 2, 3, 4, 5]);
+        let s = v.slice(1, 4);
+        assert_eq!(s.len(), 3);
+        assert_eq!(s[0], 2);
+        assert_eq!(s[2], 4);
+        assert!(v.slice(2, 2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "範囲が不正です")]
+    fn test_vector_slice_out_of_range() {
+        Vector::from_vec(vec![1, 2, 3]).slice(1, 4);
+    }
+
+    #[test]
+    fn test_vector_concat() {
+        let a = Vector::from_vec(vec![1, 2]);
+        let b = Vector::from_vec(vec![3, 4, 5]);
+        let c = a.concat(&b);
+        assert_eq!(c.len(), 5);
+        for i in 0..5 {
+            assert_eq!(c[i], i as i32 + 1);
+        }
+    }
+
+    #[test]
+    fn test_vector_outer() {
+        let a = Vector::from_vec(vec![1, 2]);
+        let b = Vector::from_vec(vec![3, 4]);
+        let m = a.outer(&b);
+        assert_eq!(m.shape(), (2, 2));
+        assert_eq!(m[(0, 0)], 3);
+        assert_eq!(m[(0, 1)], 4);
+        assert_eq!(m[(1, 0)], 6);
+        assert_eq!(m[(1, 1)], 8);
+
+        let c = Vector::from_vec(vec![1.0, 2.0, 3.0]);
+        assert_eq!(c.outer(&Vector::from_vec(vec![2.0])).shape(), (3, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "要素数が一致しません")]
+    fn test_vector_reshape_size_mismatch() {
+        Vector::from_vec(vec![1.0, 2.0, 3.0]).reshape(2, 2);
+    }
+
+    #[test]
+    fn test_vector_conjugate() {
+        let v = Vector::from_vec(vec![Complex::new(1.0, 2.0), Complex::new(-3.0, -4.0)]);
+        let c = v.conjugate();
+        assert_eq!(c[0], Complex::new(1.0, -2.0));
+        assert_eq!(c[1], Complex::new(-3.0, 4.0));
+        // v と共役の内積は各要素の絶対値の2乗和となる
+        assert_eq!(v.dot(&c), Complex::new(30.0, 0.0));
+    }
 }
@@ -0,0 +1,148 @@
+use crate::vector::Vector;
+use std::f64::consts::PI;
+
+/// Hann窓を計算する関数
+///
+/// `w_i = 0.5 (1 - cos(2πi / (n - 1)))` （`i = 0, 1, ..., n - 1`）を返す。両端は0となる。
+/// `n = 1` の場合は `[1.0]` を返す。
+///
+/// # 引数
+///
+/// * `n` - 窓の長さ
+///
+/// # 戻り値
+///
+/// 窓の係数
+pub fn hann(n: usize) -> Vector<f64> {
+    cosine_window(n, 0.5)
+}
+
+/// Hamming窓を計算する関数
+///
+/// `w_i = 0.54 - 0.46 cos(2πi / (n - 1))` （`i = 0, 1, ..., n - 1`）を返す。両端は0.08となる。
+/// `n = 1` の場合は `[1.0]` を返す。
+///
+/// # 引数
+///
+/// * `n` - 窓の長さ
+///
+/// # 戻り値
+///
+/// 窓の係数
+pub fn hamming(n: usize) -> Vector<f64> {
+    cosine_window(n, 0.54)
+}
+
+/// Tukey窓（コサインテーパー窓）を計算する関数
+///
+/// 窓の両端からそれぞれ全長の `alpha / 2` の区間を余弦で0から1まで立ち上げ、中央部は1とする。
+/// `alpha = 0` で矩形窓、`alpha = 1` でHann窓と一致する。
+/// `n = 1` の場合は `[1.0]` を返す。
+///
+/// # 引数
+///
+/// * `n` - 窓の長さ
+/// * `alpha` - テーパー部分の割合
+///
+/// # 戻り値
+///
+/// 窓の係数
+///
+/// # パニック
+///
+/// `alpha` が0以上1以下でない場合にパニックする
+pub fn tukey(n: usize, alpha: f64) -> Vector<f64> {
+    assert!(
+        (0.0..=1.0).contains(&alpha),
+        "テーパーの割合が範囲外です: {}",
+        alpha
+    );
+    if n == 1 {
+        return Vector::from_vec(vec![1.0]);
+    }
+    Vector::from_fn(n, |i| {
+        // 両端からの相対位置を用いて左右対称に計算する
+        let x: f64 = (i.min(n - 1 - i)) as f64 / (n - 1) as f64;
+        if x < alpha / 2.0 {
+            0.5 * (1.0 - (2.0 * PI * x / alpha).cos())
+        } else {
+            1.0
+        }
+    })
+}
+
+/// `w_i = a - (1 - a) cos(2πi / (n - 1))` の形の窓を計算する
+fn cosine_window(n: usize, a: f64) -> Vector<f64> {
+    if n == 1 {
+        return Vector::from_vec(vec![1.0]);
+    }
+    Vector::from_fn(n, |i| {
+        let x: f64 = (i.min(n - 1 - i)) as f64 / (n - 1) as f64;
+        a - (1.0 - a) * (2.0 * PI * x).cos()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 窓が左右対称であることを確認する
+    fn assert_symmetric(w: &Vector<f64>) {
+        let n: usize = w.len();
+        for i in 0..n {
+            assert_eq!(w[i], w[n - 1 - i], "i: {}", i);
+        }
+    }
+
+    #[test]
+    /// Hann窓の両端が0、中央が1で左右対称であることを確認するテスト
+    fn test_hann() {
+        let w = hann(9);
+        assert_eq!(w.len(), 9);
+        assert!(w[0].abs() < 1e-15);
+        assert!(w[8].abs() < 1e-15);
+        assert!((w[4] - 1.0).abs() < 1e-15);
+        assert!((w[2] - 0.5).abs() < 1e-15);
+        assert_symmetric(&w);
+        assert_symmetric(&hann(10));
+        assert_eq!(hann(1)[0], 1.0);
+        assert_eq!(hann(0).len(), 0);
+    }
+
+    #[test]
+    /// Hamming窓の両端が0.08、中央が1で左右対称であることを確認するテスト
+    fn test_hamming() {
+        let w = hamming(11);
+        assert!((w[0] - 0.08).abs() < 1e-15);
+        assert!((w[10] - 0.08).abs() < 1e-15);
+        assert!((w[5] - 1.0).abs() < 1e-15);
+        assert_symmetric(&w);
+        assert_symmetric(&hamming(8));
+    }
+
+    #[test]
+    /// Tukey窓の両端が0、中央部が1で、alphaが0と1で矩形窓とHann窓に一致することを確認するテスト
+    fn test_tukey() {
+        let w = tukey(21, 0.5);
+        assert!(w[0].abs() < 1e-15);
+        assert!(w[20].abs() < 1e-15);
+        for i in 5..=15 {
+            assert_eq!(w[i], 1.0, "i: {}", i);
+        }
+        assert!(w[2] > 0.0 && w[2] < 1.0);
+        assert_symmetric(&w);
+
+        let rectangular = tukey(16, 0.0);
+        for i in 0..16 {
+            assert_eq!(rectangular[i], 1.0);
+        }
+        assert!(tukey(16, 1.0).approx_eq(&hann(16), 1e-12));
+    }
+
+    #[test]
+    #[should_panic(expected = "テーパーの割合が範囲外です")]
+    /// テーパーの割合が範囲外の場合にパニックすることを確認するテスト
+    fn test_tukey_invalid_alpha() {
+        tukey(8, 1.5);
+    }
+}